@@ -4,12 +4,73 @@
 /// la metodología CONADESUCA para la reducción de emisiones por caña de azúcar
 /// sin quemar en Xochitepec, Morelos.
 
-use soroban_sdk::{contract, contractimpl, contracttype, contracterror, contractevent, Address, BytesN, Env, Vec, IntoVal};
+use soroban_sdk::{contract, contractimpl, contracttype, contracterror, contractevent, panic_with_error, symbol_short, Address, BytesN, Env, IntoVal, Map, String, Symbol, TryFromVal, Val, Vec};
+use stellar_access::access_control::{self as access_control, AccessControl, AccessControlError, AccessControlStorageKey};
+use stellar_macros::default_impl;
+use stellar_contract_utils::pausable::{self as pausable, Pausable};
+
+use crate::token::CarbonTokenClient;
 
 #[contract]
 pub struct CarbonCertifier;
 
-// Cliente simple para CarbonToken - usaremos invoke_contract directamente
+/// Kilogramos de CO2e por tonelada
+pub const KG_PER_TON: u128 = 1_000;
+
+/// Unidades mínimas de CXO por kilogramo de CO2e
+/// 
+/// CXO tiene 7 decimales (igual que los stroops de Stellar): 1 CXO = 10^7
+/// unidades = 1 tonelada, por lo que 1 kg = 10^7 / 1000 = 10^4 unidades.
+pub const CXO_UNITS_PER_KG: u128 = 10_000;
+
+/// Vigencia por defecto de las propuestas de certificado (~1 día a 5 s por ledger)
+const DEFAULT_PROPOSAL_TTL_LEDGERS: u32 = 17_280;
+
+/// Denominador de los porcentajes en puntos base (10_000 = 100%)
+const BPS_DENOMINATOR: u32 = 10_000;
+
+/// TTL restante por debajo del cual se renuevan las entradas (~30 días)
+const DEFAULT_TTL_THRESHOLD_LEDGERS: u32 = 518_400;
+
+/// TTL al que se extienden las entradas renovadas (~120 días)
+const DEFAULT_TTL_EXTEND_TO_LEDGERS: u32 = 2_073_600;
+
+/// Versión del esquema de almacenamiento que implementa este código
+/// 
+/// * `1` - `VerificationRecord` original, con el CO2e en toneladas (`LegacyVerificationRecord`)
+/// * `2` - Formato vigente de `VerificationRecord`
+/// * `3` - Admin y roles gestionados por `stellar-access`
+pub const SCHEMA_VERSION: u32 = 3;
+
+/// Máximo de certificados por llamada a `migrate`
+const MAX_MIGRATION_BATCH: u32 = 50;
+
+/// Rol que administra el registro de verificadores, parcelas, metodologías y factores de emisión
+pub const REGISTRY_MANAGER_ROLE: &str = "registry_manager";
+
+/// Rol de los verificadores acreditados; se otorga y retira junto con su registro
+pub const VERIFIER_ROLE: &str = "verifier";
+
+/// Rol que revoca y expira certificados y resuelve impugnaciones
+pub const REVOKER_ROLE: &str = "revoker";
+
+/// Rol que configura el token CXO, el quórum, la ventana de impugnación y el TTL
+pub const CONFIG_MANAGER_ROLE: &str = "config_manager";
+
+/// Rol que puede pausar y reanudar el contrato
+pub const PAUSER_ROLE: &str = "pauser";
+
+/// Rol que actualiza el código del contrato y migra sus records
+pub const UPGRADER_ROLE: &str = "upgrader";
+
+/// Roles operativos que pasan del admin anterior al nuevo al aceptar un traspaso
+const OPERATIONAL_ROLES: [&str; 5] = [
+    REGISTRY_MANAGER_ROLE,
+    CONFIG_MANAGER_ROLE,
+    REVOKER_ROLE,
+    PAUSER_ROLE,
+    UPGRADER_ROLE,
+];
 
 /// Errores del contrato
 #[contracterror]
@@ -23,8 +84,48 @@ pub enum ContractError {
     InvalidInput = 3,
     /// El llamador no es el propietario del certificado
     NotOwner = 4,
-    /// El llamador no está autorizado (no es admin)
+    /// El llamador no está autorizado (no es admin o no tiene el rol requerido)
     NotAuthorized = 5,
+    /// El verificador no está registrado o está suspendido
+    VerifierNotAuthorized = 6,
+    /// No se pudieron acuñar los tokens CXO (token no configurado o la llamada falló)
+    TokenMintFailed = 7,
+    /// No se pudieron quemar los tokens CXO (p. ej. el propietario ya no tiene suficientes)
+    TokenBurnFailed = 8,
+    /// El certificado fue revocado y ya no puede modificarse
+    CertificateRevoked = 9,
+    /// El estado actual del certificado no permite la operación
+    InvalidStatusTransition = 10,
+    /// El tonelaje del certificado exige aprobación por quórum de verificadores
+    QuorumRequired = 11,
+    /// La propuesta de certificado expiró sin alcanzar el quórum
+    ProposalExpired = 12,
+    /// El verificador ya aprobó la propuesta
+    AlreadyApproved = 13,
+    /// La ventana de impugnación del certificado sigue abierta
+    ChallengeWindowOpen = 14,
+    /// La ventana de impugnación del certificado ya cerró
+    ChallengeWindowClosed = 15,
+    /// El certificado tiene una impugnación pendiente de resolución
+    CertificateChallenged = 16,
+    /// No hay factor de emisión configurado para la región y zafra
+    EmissionFactorNotSet = 17,
+    /// El CO2e declarado excede la cota de la metodología CONADESUCA
+    Co2eExceedsEstimate = 18,
+    /// La versión de metodología no está registrada
+    MethodologyNotFound = 19,
+    /// El record no referencia la versión de metodología vigente
+    MethodologyVersionMismatch = 20,
+    /// La parcela no está registrada
+    ParcelNotFound = 21,
+    /// La parcela no pertenece al agricultor del record
+    ParcelFarmerMismatch = 22,
+    /// Las hectáreas declaradas exceden la superficie registrada de la parcela
+    HectaresExceedParcelArea = 23,
+    /// La parcela ya tiene un certificado para la zafra
+    ParcelSeasonAlreadyCertified = 24,
+    /// No quedan IDs de certificado libres en la secuencia
+    CertificateIdsExhausted = 26,
 }
 
 /// Eventos del contrato
@@ -37,8 +138,8 @@ pub struct CertificateMintedEvent {
     pub farmer: Address,
     /// Dirección del verificador autorizado
     pub verifier: Address,
-    /// Toneladas de CO2e acuñadas
-    pub tons_minted: u128,
+    /// Kilogramos de CO2e acuñados
+    pub co2e_kg_minted: u128,
     /// Timestamp de la acuñación
     pub timestamp: u64,
 }
@@ -63,16 +164,312 @@ pub struct CertificateBurnedEvent {
     pub certificate_id: u32,
     /// Dirección que quemó el certificado
     pub burned_by: Address,
-    /// Toneladas de CO2e retiradas
-    pub co2e_tons_retired: u128,
+    /// Kilogramos de CO2e retirados
+    pub co2e_kg_retired: u128,
+    /// ID del recibo de retiro emitido
+    pub receipt_id: u32,
+}
+
+/// Evento de retiro parcial de un certificado de carbono
+#[contractevent]
+#[derive(Clone)]
+pub struct CertificateRetiredEvent {
+    /// ID único del certificado
+    pub certificate_id: u32,
+    /// Propietario que retiró el CO2e
+    pub retired_by: Address,
+    /// Beneficiario en cuyo nombre se compensa el CO2e
+    pub beneficiary: Address,
+    /// Kilogramos de CO2e retirados en esta operación
+    pub co2e_kg_retired: u128,
+    /// Kilogramos de CO2e que permanecen vigentes en el certificado
+    pub co2e_kg_remaining: u128,
+    /// ID del recibo de retiro emitido
+    pub receipt_id: u32,
+}
+
+/// Evento de revocación de un certificado fraudulento o inválido
+#[contractevent]
+#[derive(Clone)]
+pub struct CertificateRevokedEvent {
+    /// ID del certificado revocado
+    pub certificate_id: u32,
+    /// Verificador emisor o admin que revocó
+    pub revoked_by: Address,
+    /// Hash del documento que justifica la revocación
+    pub reason_hash: BytesN<32>,
+    /// Kilogramos de CO2e excluidos del total
+    pub co2e_kg_revoked: u128,
+    /// Unidades de CXO recuperadas del agricultor
+    pub cxo_clawed_back: i128,
+}
+
+/// Evento de expiración de un certificado
+#[contractevent]
+#[derive(Clone)]
+pub struct CertificateExpiredEvent {
+    /// ID del certificado expirado
+    pub certificate_id: u32,
+    /// Kilogramos de CO2e excluidos del total
+    pub co2e_kg_expired: u128,
+    /// Unidades de CXO recuperadas del agricultor
+    pub cxo_clawed_back: i128,
+}
+
+/// Evento de impugnación de un certificado pendiente
+#[contractevent]
+#[derive(Clone)]
+pub struct CertificateChallengedEvent {
+    /// ID del certificado impugnado
+    pub certificate_id: u32,
+    /// Verificador o admin que impugna
+    pub challenger: Address,
+    /// Hash de la evidencia presentada
+    pub evidence_hash: BytesN<32>,
+}
+
+/// Evento de resolución de una impugnación por el rol `revoker`
+#[contractevent]
+#[derive(Clone)]
+pub struct ChallengeResolvedEvent {
+    /// ID del certificado impugnado
+    pub certificate_id: u32,
+    /// `true` si la impugnación procede y el certificado se revoca
+    pub upheld: bool,
+}
+
+/// Evento de activación de un certificado pendiente y acuñación de sus CXO
+#[contractevent]
+#[derive(Clone)]
+pub struct CertificateFinalizedEvent {
+    /// ID del certificado activado
+    pub certificate_id: u32,
+    /// Agricultor que recibe los CXO
+    pub farmer: Address,
+    /// Kilogramos de CO2e acreditados
+    pub co2e_kg: u128,
+}
+
+/// Evento de configuración de un factor de emisión por metodología, región y zafra
+#[contractevent]
+#[derive(Clone)]
+pub struct EmissionFactorSetEvent {
+    /// Versión de metodología a la que aplica el factor (0 sin metodología registrada)
+    pub methodology_version: u32,
+    /// Región a la que aplica el factor
+    pub region: Symbol,
+    /// Zafra a la que aplica el factor
+    pub season: u32,
+    /// Kilogramos de CO2e evitados por hectárea no quemada
+    pub kg_co2e_per_hectare: u128,
+}
+
+/// Evento de registro de una parcela
+#[contractevent]
+#[derive(Clone)]
+pub struct ParcelRegisteredEvent {
+    /// ID de la parcela
+    pub parcel_id: u32,
+    /// Agricultor titular
+    pub farmer: Address,
+    /// Superficie registrada en hectáreas
+    pub area_hectares: u32,
+}
+
+/// Evento de registro de una nueva versión de metodología
+#[contractevent]
+#[derive(Clone)]
+pub struct MethodologyRegisteredEvent {
+    /// Versión asignada (pasa a ser la vigente)
+    pub version: u32,
+    /// Factor de emisión por defecto en kg CO2e por hectárea
+    pub kg_co2e_per_hectare: u128,
+    /// Porcentaje de buffer en puntos base
+    pub buffer_bps: u32,
+    /// Descuento por incertidumbre en puntos base
+    pub uncertainty_discount_bps: u32,
+}
+
+/// Evento de propuesta de certificado pendiente de quórum
+#[contractevent]
+#[derive(Clone)]
+pub struct CertificateProposedEvent {
+    /// ID del certificado propuesto
+    pub certificate_id: u32,
+    /// Verificador que propone
+    pub verifier: Address,
+    /// Kilogramos de CO2e propuestos
+    pub co2e_kg: u128,
+    /// Último ledger en el que la propuesta puede aprobarse
+    pub expires_at_ledger: u32,
+}
+
+/// Evento de aprobación de una propuesta por un verificador
+#[contractevent]
+#[derive(Clone)]
+pub struct ProposalApprovedEvent {
+    /// ID del certificado propuesto
+    pub certificate_id: u32,
+    /// Verificador que aprueba
+    pub verifier: Address,
+    /// Número de aprobaciones de verificadores que siguen activos
+    pub approvals: u32,
+}
+
+/// Evento de actualización de la configuración de quórum
+#[contractevent]
+#[derive(Clone)]
+pub struct QuorumConfigUpdatedEvent {
+    /// Aprobaciones requeridas (M)
+    pub required_approvals: u32,
+    /// Kilogramos de CO2e a partir de los cuales se exige quórum
+    pub threshold_kg: u128,
+    /// Vigencia de las propuestas en ledgers
+    pub proposal_ttl_ledgers: u32,
+}
+
+/// Evento de actualización de la ventana de impugnación
+#[contractevent]
+#[derive(Clone)]
+pub struct ChallengeWindowUpdatedEvent {
+    /// Duración de la ventana en ledgers (0 = sin ventana)
+    pub ledgers: u32,
+}
+
+/// Evento de actualización de la configuración de TTL del almacenamiento
+#[contractevent]
+#[derive(Clone)]
+pub struct TtlConfigUpdatedEvent {
+    /// TTL restante en ledgers por debajo del cual se renueva una entrada
+    pub threshold_ledgers: u32,
+    /// TTL en ledgers al que se extiende una entrada renovada
+    pub extend_to_ledgers: u32,
+}
+
+/// Evento de división de un certificado en certificados hijos
+#[contractevent]
+#[derive(Clone)]
+pub struct CertificateSplitEvent {
+    /// ID del certificado padre dividido
+    pub certificate_id: u32,
+    /// IDs de los certificados hijos creados
+    pub child_ids: Vec<u32>,
+}
+
+/// Evento de fusión de certificados con la misma procedencia
+#[contractevent]
+#[derive(Clone)]
+pub struct CertificatesMergedEvent {
+    /// IDs de los certificados fusionados
+    pub certificate_ids: Vec<u32>,
+    /// ID del certificado resultante
+    pub merged_id: u32,
+}
+
+/// Evento de alta de un verificador en el registro
+#[contractevent]
+#[derive(Clone)]
+pub struct VerifierAddedEvent {
+    /// Dirección del verificador registrado
+    pub verifier: Address,
+    /// Nombre de la organización acreditada
+    pub organization_name: String,
+}
+
+/// Evento de cambio de estado de un verificador (suspensión o reactivación)
+#[contractevent]
+#[derive(Clone)]
+pub struct VerifierStatusChangedEvent {
+    /// Dirección del verificador
+    pub verifier: Address,
+    /// Nuevo estado del verificador
+    pub status: VerifierStatus,
+}
+
+/// Evento de baja de un verificador del registro
+#[contractevent]
+#[derive(Clone)]
+pub struct VerifierRemovedEvent {
+    /// Dirección del verificador eliminado
+    pub verifier: Address,
+}
+
+/// Evento de migración de un certificado al formato con vintage y ubicación
+#[contractevent]
+#[derive(Clone)]
+pub struct CertificateMigratedEvent {
+    /// ID del certificado migrado
+    pub certificate_id: u32,
+    /// Año de cosecha asignado
+    pub vintage: u32,
+    /// Proyecto / ingenio asignado
+    pub project_id: Symbol,
+}
+
+/// Evento de actualización del código (WASM) del contrato
+#[contractevent]
+#[derive(Clone)]
+pub struct ContractUpgradedEvent {
+    /// Hash del nuevo WASM instalado
+    pub new_wasm_hash: BytesN<32>,
+}
+
+/// Evento de cierre de una migración del esquema de almacenamiento
+#[contractevent]
+#[derive(Clone)]
+pub struct SchemaMigratedEvent {
+    /// Versión de esquema anterior
+    pub from_version: u32,
+    /// Versión de esquema vigente
+    pub to_version: u32,
+}
+
+/// Evento de propuesta de traspaso de la administración
+#[contractevent]
+#[derive(Clone)]
+pub struct AdminTransferProposedEvent {
+    /// Administrador actual
+    pub current_admin: Address,
+    /// Administrador propuesto
+    pub new_admin: Address,
+    /// Último ledger en que el traspaso puede aceptarse
+    pub expiration_ledger: u32,
+}
+
+/// Evento de traspaso de la administración aceptado
+#[contractevent]
+#[derive(Clone)]
+pub struct AdminTransferredEvent {
+    /// Administrador anterior
+    pub previous_admin: Address,
+    /// Nuevo administrador
+    pub new_admin: Address,
+}
+
+/// Evento de cancelación de un traspaso de administración pendiente
+#[contractevent]
+#[derive(Clone)]
+pub struct AdminTransferCancelledEvent {
+    /// Administrador que cancela
+    pub admin: Address,
+    /// Administrador que había sido propuesto
+    pub new_admin: Address,
+}
+
+/// Evento de renuncia definitiva a la administración
+#[contractevent]
+#[derive(Clone)]
+pub struct AdminRenouncedEvent {
+    /// Último administrador
+    pub admin: Address,
 }
 
 /// Criterios de ordenamiento para listado de certificados
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum SortBy {
-    /// Ordenar por toneladas de CO2e
-    Co2eTons,
+    /// Ordenar por kilogramos de CO2e
+    Co2eKg,
     /// Ordenar por hectáreas no quemadas
     Hectares,
     /// Ordenar por ID de certificado
@@ -89,7 +486,7 @@ pub enum DataKey {
     Certificates(u32),
     /// Contador total de certificados en Instance Storage
     TotalCertificates,
-    /// Contador total de CO2e acuñado en Instance Storage
+    /// Contador total de CO2e acuñado en kilogramos (Instance Storage)
     TotalCO2e,
     /// Índice de certificados por agricultor (Persistent Storage)
     FarmerCertList(Address),
@@ -99,8 +496,109 @@ pub enum DataKey {
     CertificateOwner(u32),
     /// ID del contrato de token fungible CARBONXO (Instance Storage)
     TokenContractId,
-    /// Dirección del administrador del contrato (Instance Storage)
-    Admin,
+    /// Registro de verificadores acreditados por dirección (Persistent Storage)
+    Verifier(Address),
+    /// Kilogramos de CO2e retirados parcialmente de cada certificado (Persistent Storage)
+    CertificateRetired(u32),
+    /// Contador total de CO2e retirado en kilogramos (Instance Storage)
+    TotalRetired,
+    /// Certificado padre de un certificado creado por división o fusión (Persistent Storage)
+    CertificateParent(u32),
+    /// Siguiente ID candidato para certificados creados por el contrato (Instance Storage)
+    NextCertificateId,
+    /// Recibos de retiro permanentes por ID (Persistent Storage)
+    RetirementReceipt(u32),
+    /// Contador total de recibos de retiro emitidos (Instance Storage)
+    TotalReceipts,
+    /// Índice de recibos de retiro por beneficiario (Persistent Storage)
+    BeneficiaryReceiptList(Address),
+    /// Registro de revocación de un certificado (Persistent Storage)
+    Revocation(u32),
+    /// Estado del ciclo de vida de cada certificado (Persistent Storage)
+    Status(u32),
+    /// Configuración de quórum de verificadores (Instance Storage)
+    QuorumConfig,
+    /// Propuestas de certificado pendientes de quórum por ID (Persistent Storage)
+    Proposal(u32),
+    /// Duración de la ventana de impugnación en ledgers (Instance Storage)
+    ChallengeWindow,
+    /// Último ledger de la ventana de impugnación de cada certificado pendiente (Persistent Storage)
+    ChallengeDeadline(u32),
+    /// Impugnación abierta sobre un certificado pendiente (Persistent Storage)
+    Challenge(u32),
+    /// Factor de emisión en kg CO2e por hectárea, por versión de metodología, región y zafra (Persistent Storage)
+    EmissionFactor(u32, Symbol, u32),
+    /// Parámetros de metodología por versión; nunca se sobrescriben (Persistent Storage)
+    Methodology(u32),
+    /// Versión de metodología vigente (Instance Storage)
+    CurrentMethodology,
+    /// Registro de parcelas por ID (Persistent Storage)
+    Parcel(u32),
+    /// Certificado emitido para cada (parcela, zafra) (Persistent Storage)
+    ParcelSeason(u32, u32),
+    /// Índice de certificados por año de cosecha (Persistent Storage)
+    VintageCertList(u32),
+    /// Índice de certificados por proyecto / ingenio (Persistent Storage)
+    ProjectCertList(Symbol),
+    /// Umbral y extensión del TTL de almacenamiento (Instance Storage)
+    TtlConfig,
+    /// Versión del esquema de almacenamiento (Instance Storage)
+    SchemaVersion,
+}
+
+/// Estado del ciclo de vida de un certificado
+/// 
+/// Transiciones permitidas:
+/// * `Pending` -> `Active` | `Revoked`
+/// * `Active` -> `Retired` | `Revoked` | `Expired` | `Split` | `Merged`
+/// 
+/// `Retired`, `Revoked`, `Expired`, `Split` y `Merged` son estados finales. El
+/// estado de un certificado retirado, dividido o fusionado se conserva aunque
+/// su record se elimine, de modo que su ID no vuelve a acuñarse.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CertificateStatus {
+    /// En ventana de impugnación: sin CO2e acreditado ni CXO acuñados
+    Pending,
+    /// Vigente: puede transferirse, dividirse, fusionarse y retirarse
+    Active,
+    /// Retirado por completo (compensación realizada)
+    Retired,
+    /// Revocado por fraude o invalidez
+    Revoked,
+    /// Expirado por el rol `revoker`; ya no puede retirarse
+    Expired,
+    /// Dividido en certificados hijos con `split_certificate`
+    Split,
+    /// Fusionado en un nuevo certificado con `merge_certificates`
+    Merged,
+}
+
+/// Estado de un verificador dentro del registro
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VerifierStatus {
+    /// Puede acuñar certificados
+    Active,
+    /// Temporalmente sin permisos de acuñación
+    Suspended,
+}
+
+/// Metadatos de un verificador acreditado
+/// 
+/// Administrado por el rol `registry_manager`; solo los verificadores con estado
+/// `Active` y el rol `verifier` pueden acuñar certificados.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerifierInfo {
+    /// Nombre de la organización (p. ej. Ingenio Emiliano Zapata/ULPCA)
+    pub organization_name: String,
+    /// Hash SHA-256 del documento de acreditación off-chain
+    pub accreditation_hash: BytesN<32>,
+    /// Estado actual del verificador
+    pub status: VerifierStatus,
+    /// Timestamp del registro
+    pub registered_at: u64,
 }
 
 /// Datos de verificación on-chain del certificado de carbono
@@ -119,12 +617,226 @@ pub struct VerificationRecord {
     /// Superficie No Quemada (SQ) en hectáreas - Variable clave para el cálculo de CO2e
     pub hectares_not_burned: u32,
     
-    /// Toneladas de CO2e reducidas (1 unidad = 1 tonelada de CO2e)
-    pub co2e_tons: u128,
+    /// Kilogramos de CO2e reducidos (1000 unidades = 1 tonelada de CO2e)
+    pub co2e_kg: u128,
     
     /// Hash SHA-256 del informe MRV (Measurement, Reporting, Verification) off-chain
     /// Garantiza la inmutabilidad de la evidencia del certificado
     pub metadata_hash: BytesN<32>,
+    
+    /// Región de la parcela (p. ej. `morelos`), para el factor de emisión aplicable
+    pub region: Symbol,
+    
+    /// Zafra (año de inicio de la temporada de cosecha, p. ej. 2024 para 2024/25)
+    pub season: u32,
+    
+    /// Versión de metodología bajo la que se emitió (0 si no hay ninguna registrada)
+    pub methodology_version: u32,
+    
+    /// Parcela registrada de la que provienen las hectáreas (una por zafra)
+    pub parcel_id: u32,
+    
+    /// Año de cosecha (vintage) en que ocurrió la reducción de emisiones
+    pub vintage: u32,
+    
+    /// Inicio del periodo de monitoreo (timestamp Unix)
+    pub monitoring_start: u64,
+    
+    /// Fin del periodo de monitoreo (timestamp Unix)
+    pub monitoring_end: u64,
+    
+    /// Identificador del proyecto / ingenio (p. ej. `ezapata`)
+    pub project_id: Symbol,
+    
+    /// Municipio de la parcela (p. ej. Xochitepec)
+    pub municipality: String,
+}
+
+/// Formato original de `VerificationRecord` (esquema `1`), con el CO2e en
+/// toneladas enteras
+/// 
+/// Solo se usa para leer certificados almacenados antes de la migración
+/// (ver `migrate_certificate_record`).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyVerificationRecord {
+    pub verifier_address: Address,
+    pub farmer_address: Address,
+    pub hectares_not_burned: u32,
+    pub co2e_tons: u128,
+    pub metadata_hash: BytesN<32>,
+}
+
+/// Parcela de caña de azúcar registrada
+/// 
+/// Cada parcela admite un único certificado por zafra, lo que evita contar
+/// dos veces la misma superficie no quemada.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParcelInfo {
+    /// Agricultor titular de la parcela
+    pub farmer: Address,
+    /// Hash del polígono georreferenciado de la parcela
+    pub geo_hash: BytesN<32>,
+    /// Superficie total en hectáreas
+    pub area_hectares: u32,
+    /// Timestamp del registro
+    pub registered_at: u64,
+}
+
+/// Parámetros de una versión de la metodología CONADESUCA
+/// 
+/// Cada versión es inmutable: los cambios de metodología se registran como una
+/// versión nueva, de modo que los certificados históricos conservan la suya.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MethodologyParams {
+    /// Factor de emisión por defecto (kg CO2e por hectárea) si la región/zafra
+    /// no tiene uno propio para esta versión
+    pub kg_co2e_per_hectare: u128,
+    /// Porcentaje de buffer (reserva contra reversiones) en puntos base
+    pub buffer_bps: u32,
+    /// Descuento por incertidumbre de la medición en puntos base
+    pub uncertainty_discount_bps: u32,
+}
+
+/// Configuración del quórum M-de-N de verificadores
+/// 
+/// Los certificados con `co2e_kg >= threshold_kg` requieren `required_approvals`
+/// firmas de verificadores activos distintos (M de los N registrados). Con
+/// `required_approvals = 1` (valor por defecto) no se exige quórum.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QuorumConfig {
+    /// Aprobaciones requeridas, incluyendo la del verificador que propone (M)
+    pub required_approvals: u32,
+    /// Kilogramos de CO2e a partir de los cuales se exige quórum
+    pub threshold_kg: u128,
+    /// Vigencia de las propuestas en ledgers desde su creación
+    pub proposal_ttl_ledgers: u32,
+}
+
+/// Configuración de la extensión de TTL del almacenamiento
+/// 
+/// Cada lectura o escritura de un certificado, su propietario o una lista de
+/// índice renueva la entrada (y la instancia del contrato) hasta
+/// `extend_to_ledgers` cuando su TTL restante cae por debajo de `threshold_ledgers`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TtlConfig {
+    /// TTL restante en ledgers por debajo del cual se renueva una entrada
+    pub threshold_ledgers: u32,
+    /// TTL en ledgers al que se extiende una entrada renovada
+    pub extend_to_ledgers: u32,
+}
+
+/// Propuesta de certificado pendiente de quórum
+/// 
+/// Mientras está pendiente no existe propietario, índices, contadores ni CXO;
+/// todo se aplica al alcanzar el quórum.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CertificateProposal {
+    /// Datos del certificado propuesto
+    pub record: VerificationRecord,
+    /// Verificadores que aprobaron (el primero es quien propuso)
+    pub approvals: Vec<Address>,
+    /// Aprobaciones requeridas al momento de proponer
+    pub required_approvals: u32,
+    /// Último ledger en el que la propuesta puede aprobarse
+    pub expires_at_ledger: u32,
+}
+
+/// Impugnación de un certificado durante su ventana de disputa
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Challenge {
+    /// Verificador registrado o admin que impugna
+    pub challenger: Address,
+    /// Hash de la evidencia presentada (p. ej. imágenes satelitales)
+    pub evidence_hash: BytesN<32>,
+    /// Ledger en el que se presentó la impugnación
+    pub raised_at_ledger: u32,
+}
+
+/// Registro de la revocación de un certificado
+/// 
+/// El certificado revocado se conserva en el almacenamiento, pero ya no puede
+/// transferirse, retirarse, dividirse ni fusionarse.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RevocationRecord {
+    /// Verificador emisor o admin que revocó
+    pub revoked_by: Address,
+    /// Hash del documento que justifica la revocación (p. ej. imágenes satelitales)
+    pub reason_hash: BytesN<32>,
+    /// Timestamp del ledger al momento de la revocación
+    pub timestamp: u64,
+    /// Kilogramos de CO2e vigentes excluidos del total
+    pub co2e_kg_revoked: u128,
+    /// Unidades de CXO recuperadas (clawback) del agricultor
+    pub cxo_clawed_back: i128,
+    /// Unidades de CXO que el agricultor ya no tenía y siguen en circulación
+    pub cxo_unrecovered: i128,
+}
+
+/// Recibo permanente de retiro de CO2e
+/// 
+/// Se emite en cada quema o retiro parcial y no se elimina nunca, para que
+/// terceros puedan verificar las compensaciones declaradas después del hecho.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RetirementReceipt {
+    /// ID único del recibo
+    pub receipt_id: u32,
+    /// ID del certificado retirado
+    pub certificate_id: u32,
+    /// Propietario que retiró el CO2e
+    pub retired_by: Address,
+    /// Beneficiario en cuyo nombre se compensa el CO2e
+    pub beneficiary: Address,
+    /// Nombre del beneficiario (p. ej. razón social)
+    pub beneficiary_name: String,
+    /// Motivo del retiro (p. ej. "Compensación de emisiones 2024")
+    pub reason: String,
+    /// Timestamp del ledger al momento del retiro
+    pub timestamp: u64,
+    /// Verificador que emitió el certificado original
+    pub verifier_address: Address,
+    /// Agricultor del certificado original
+    pub farmer_address: Address,
+    /// Hectáreas no quemadas del certificado original
+    pub hectares_not_burned: u32,
+    /// Hash de metadatos del certificado original
+    pub metadata_hash: BytesN<32>,
+    /// Kilogramos de CO2e retirados
+    pub co2e_kg: u128,
+    /// Kilogramos de CO2e con que se emitió el certificado (vigentes más retirados antes)
+    pub original_co2e_kg: u128,
+}
+
+/// Cantidad de CO2e expresada en unidades crudas y legibles para el frontend
+/// 
+/// Ejemplo: 12.4 t CO2e => `kg = 12400`, `tons = 12`, `remainder_kg = 400`,
+/// `cxo_units = 124_000_000` (12.4 CXO).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Co2eAmount {
+    /// Kilogramos de CO2e (unidad cruda almacenada on-chain)
+    pub kg: u128,
+    /// Toneladas completas de CO2e
+    pub tons: u128,
+    /// Kilogramos restantes después de las toneladas completas (0-999)
+    pub remainder_kg: u32,
+    /// Equivalente en unidades mínimas de CXO (7 decimales)
+    pub cxo_units: i128,
+}
+
+/// Datos del beneficiario de un retiro que se registran en su recibo
+struct RetirementClaim {
+    beneficiary: Address,
+    beneficiary_name: String,
+    reason: String,
 }
 
 #[contractimpl]
@@ -137,65 +849,219 @@ impl CarbonCertifier {
 
     /// Inicializa el contrato con un administrador
     /// 
+    /// El admin recibe además los roles operativos `registry_manager`,
+    /// `config_manager` y `pauser`, que luego puede delegar con `grant_role` y
+    /// `revoke_role`. Los roles `revoker` y `upgrader` se otorgan de forma
+    /// explícita con `grant_role`.
+    /// 
     /// # Argumentos
     /// * `env` - El entorno del contrato
     /// * `admin` - Dirección del administrador
     /// 
     /// # Errores
     /// * `ContractError::AlreadyExists` si el contrato ya ha sido inicializado
+    ///   (aunque su admin haya renunciado)
     pub fn initialize(env: Env, admin: Address) -> Result<(), ContractError> {
-        // Verificar que no ha sido inicializado
-        if env.storage().instance().has(&DataKey::Admin) {
+        // Verificar que no ha sido inicializado (aunque su admin haya renunciado)
+        if env.storage().instance().has(&DataKey::SchemaVersion) || access_control::get_admin(&env).is_some() {
             return Err(ContractError::AlreadyExists);
         }
 
-        // Guardar el admin en Instance Storage
-        env.storage().instance().set(&DataKey::Admin, &admin);
+        // ✅ ROLES: El admin y sus roles los guarda stellar-access
+        access_control::set_admin(&env, &admin);
+        Self::grant_operational_roles(&env, &admin);
+
+        env.storage().instance().set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+        Self::extend_instance_ttl(&env);
 
         Ok(())
     }
 
-    /// Obtiene la dirección del administrador del contrato
-    /// 
-    /// # Retorna
-    /// `Address` - La dirección del administrador
+    /// Propone traspasar la administración a otra dirección (paso 1 de 2)
     /// 
-    /// # Errores
-    /// * `ContractError::NotFound` si el contrato no ha sido inicializado
-    pub fn get_admin(env: Env) -> Result<Address, ContractError> {
-        match env.storage().instance().get(&DataKey::Admin) {
-            Some(admin) => Ok(admin),
-            None => Err(ContractError::NotFound),
-        }
-    }
-
-    /// Obtiene los datos de un certificado de carbono por su ID
+    /// El traspaso solo ocurre cuando `new_admin` lo acepta con `accept_admin`
+    /// a más tardar en `expiration_ledger`. Una nueva propuesta reemplaza a la
+    /// pendiente. Equivale a `transfer_admin_role` de `stellar-access`.
     /// 
     /// # Argumentos
     /// * `env` - El entorno del contrato
-    /// * `certificate_id` - ID único del certificado (u32)
-    /// 
-    /// # Retorna
-    /// `VerificationRecord` - Los datos completos del certificado
+    /// * `new_admin` - Dirección propuesta (p. ej. una multisig)
+    /// * `expiration_ledger` - Último ledger en que puede aceptarse
     /// 
     /// # Errores
-    /// * `ContractError::NotFound` si el certificado no existe
-    pub fn get_certificate_data(
-        env: Env,
-        certificate_id: u32,
-    ) -> Result<VerificationRecord, ContractError> {
-        let key = DataKey::Certificates(certificate_id);
-        
-        // Intentar obtener el certificado del almacenamiento persistente
-        match env.storage().persistent().get(&key) {
-            Some(record) => Ok(record),
-            None => Err(ContractError::NotFound),
-        }
+    /// * `ContractError::NotAuthorized` si el contrato no tiene admin
+    /// * `ContractError::InvalidInput` si `new_admin` es el admin actual o la expiración ya pasó
+    /// 
+    /// # Emite
+    /// * `AdminTransferProposedEvent`
+    /// 
+    /// # Autorización
+    /// Requiere autenticación del administrador actual
+    pub fn propose_admin(env: Env, new_admin: Address, expiration_ledger: u32) -> Result<(), ContractError> {
+        // ✅ AUTORIZACIÓN: Solo el admin actual propone su reemplazo; stellar-access exige su firma
+        let admin = access_control::get_admin(&env).ok_or(ContractError::NotAuthorized)?;
+
+        Self::start_admin_transfer(&env, admin, new_admin, expiration_ledger)
     }
 
-    /// Obtiene el propietario actual de un certificado NFT
+    /// Acepta el traspaso de administración pendiente (paso 2 de 2)
     /// 
-    /// # Argumentos
+    /// Equivale a `accept_admin_transfer` de `stellar-access`. Los roles
+    /// operativos del admin anterior pasan al nuevo admin; los de otras
+    /// direcciones se gestionan con `grant_role` y `revoke_role`.
+    /// 
+    /// # Errores
+    /// * `ContractError::NotFound` si no hay traspaso pendiente o la propuesta ya expiró
+    /// 
+    /// # Emite
+    /// * `AdminTransferredEvent`
+    /// 
+    /// # Autorización
+    /// Requiere autenticación del administrador propuesto
+    pub fn accept_admin(env: Env) -> Result<(), ContractError> {
+        Self::complete_admin_transfer(&env)
+    }
+
+    /// Cancela el traspaso de administración pendiente
+    /// 
+    /// # Errores
+    /// * `ContractError::NotAuthorized` si el contrato no tiene admin
+    /// * `ContractError::NotFound` si no hay traspaso pendiente
+    /// 
+    /// # Emite
+    /// * `AdminTransferCancelledEvent`
+    /// 
+    /// # Autorización
+    /// Requiere autenticación del administrador actual
+    pub fn cancel_admin_transfer(env: Env) -> Result<(), ContractError> {
+        // ✅ AUTORIZACIÓN: Solo el admin actual cancela; stellar-access exige su firma
+        let admin = access_control::get_admin(&env).ok_or(ContractError::NotAuthorized)?;
+
+        Self::cancel_pending_admin(&env, admin)
+    }
+
+    /// Obtiene el administrador propuesto en el traspaso pendiente, si existe
+    /// 
+    /// `stellar-access` guarda la propuesta en almacenamiento temporal con vida
+    /// hasta su ledger de expiración; una propuesta expirada ya no se reporta.
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        env.storage().temporary().get(&AccessControlStorageKey::PendingAdmin)
+    }
+
+    /// Actualiza el código (WASM) del contrato conservando su almacenamiento
+    /// 
+    /// Si el nuevo código cambia el esquema, `upgrader` debe reescribir los
+    /// records con `migrate` y cerrar la migración con `finish_migration`.
+    /// 
+    /// # Argumentos
+    /// * `env` - El entorno del contrato
+    /// * `caller` - Dirección con el rol `upgrader`
+    /// * `new_wasm_hash` - Hash del WASM ya instalado en la red
+    /// 
+    /// # Errores
+    /// * `AccessControlError::Unauthorized` si el llamador no tiene el rol `upgrader`
+    /// 
+    /// # Emite
+    /// * `ContractUpgradedEvent`
+    pub fn upgrade(env: Env, caller: Address, new_wasm_hash: BytesN<32>) -> Result<(), ContractError> {
+        Self::ensure_role(&env, &caller, UPGRADER_ROLE);
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+
+        ContractUpgradedEvent { new_wasm_hash }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Obtiene la versión del esquema de almacenamiento
+    /// 
+    /// Los contratos desplegados antes de existir el marcador reportan `1`.
+    pub fn get_schema_version(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::SchemaVersion).unwrap_or(1)
+    }
+
+    /// Marca el almacenamiento como migrado al esquema de este código (`SCHEMA_VERSION`)
+    /// 
+    /// En contratos con esquema anterior a `3` el admin ya ocupa la clave de
+    /// instancia `Admin` que lee `stellar-access`, pero aún no tiene roles: antes
+    /// de migrar se otorga `upgrader` con `grant_role` y aquí recibe los roles
+    /// operativos que otorga `initialize`. `revoker` y el rol `verifier` de los
+    /// verificadores ya registrados se otorgan con `grant_role`. Desde el esquema
+    /// `1` el total de CO2e pasa de toneladas a kilogramos. Como la migración de
+    /// records, se ejecuta con el contrato pausado.
+    /// 
+    /// # Argumentos
+    /// * `env` - El entorno del contrato
+    /// * `caller` - Dirección con el rol `upgrader`
+    /// 
+    /// # Errores
+    /// * `AccessControlError::Unauthorized` si el llamador no tiene el rol `upgrader`
+    /// * `PausableError::ExpectedPause` si el contrato no está pausado
+    /// * `ContractError::AlreadyExists` si el esquema ya está al día
+    /// * `ContractError::InvalidInput` si el total de CO2e no cabe en kilogramos
+    /// 
+    /// # Emite
+    /// * `SchemaMigratedEvent`
+    pub fn finish_migration(env: Env, caller: Address) -> Result<(), ContractError> {
+        Self::ensure_role(&env, &caller, UPGRADER_ROLE);
+
+        // ✅ PAUSA: La migración se cierra sin movimientos en curso
+        pausable::when_paused(&env);
+
+        let from_version = Self::get_schema_version(env.clone());
+        if from_version >= SCHEMA_VERSION {
+            return Err(ContractError::AlreadyExists);
+        }
+
+        // ✅ UNIDADES: El esquema `1` contaba el CO2e total en toneladas
+        if from_version < 2 {
+            let total_tons: u128 = env.storage().instance().get(&DataKey::TotalCO2e).unwrap_or(0);
+            let total_kg = total_tons.checked_mul(KG_PER_TON).ok_or(ContractError::InvalidInput)?;
+            env.storage().instance().set(&DataKey::TotalCO2e, &total_kg);
+        }
+        env.storage().instance().set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+
+        // ✅ ROLES: El admin de un esquema anterior recibe los roles operativos
+        if from_version < 3 {
+            if let Some(admin) = access_control::get_admin(&env) {
+                Self::grant_operational_roles(&env, &admin);
+            }
+        }
+
+        SchemaMigratedEvent {
+            from_version,
+            to_version: SCHEMA_VERSION,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Obtiene los datos de un certificado de carbono por su ID
+    /// 
+    /// # Argumentos
+    /// * `env` - El entorno del contrato
+    /// * `certificate_id` - ID único del certificado (u32)
+    /// 
+    /// # Retorna
+    /// `VerificationRecord` - Los datos completos del certificado
+    /// 
+    /// # Errores
+    /// * `ContractError::NotFound` si el certificado no existe
+    pub fn get_certificate_data(
+        env: Env,
+        certificate_id: u32,
+    ) -> Result<VerificationRecord, ContractError> {
+        // Intentar obtener el certificado del almacenamiento persistente (renueva su TTL)
+        match Self::read_certificate(&env, certificate_id) {
+            Some(record) => Ok(record),
+            None => Err(ContractError::NotFound),
+        }
+    }
+
+    /// Obtiene el propietario actual de un certificado NFT
+    /// 
+    /// # Argumentos
     /// * `env` - El entorno del contrato
     /// * `certificate_id` - ID único del certificado (u32)
     /// 
@@ -209,14 +1075,12 @@ impl CarbonCertifier {
         certificate_id: u32,
     ) -> Result<Address, ContractError> {
         // Verificar que el certificado existe
-        let cert_key = DataKey::Certificates(certificate_id);
-        if env.storage().persistent().get::<DataKey, VerificationRecord>(&cert_key).is_none() {
+        if Self::read_certificate(&env, certificate_id).is_none() {
             return Err(ContractError::NotFound);
         }
         
         // Obtener el propietario
-        let owner_key = DataKey::CertificateOwner(certificate_id);
-        match env.storage().persistent().get(&owner_key) {
+        match Self::read_owner(&env, certificate_id) {
             Some(owner) => Ok(owner),
             None => Err(ContractError::NotFound),
         }
@@ -236,8 +1100,11 @@ impl CarbonCertifier {
     /// `()` - Éxito
     /// 
     /// # Errores
+    /// * `PausableError::EnforcedPause` si el contrato está pausado
     /// * `ContractError::NotFound` si el certificado no existe
     /// * `ContractError::NotOwner` si 'from' no es el propietario actual
+    /// * `ContractError::CertificateRevoked` si el certificado fue revocado
+    /// * `ContractError::InvalidStatusTransition` si el certificado no está activo
     /// 
     /// # Autorización
     /// Requiere autenticación de `from`
@@ -247,18 +1114,23 @@ impl CarbonCertifier {
         from: Address,
         to: Address,
     ) -> Result<(), ContractError> {
+        // ✅ PAUSA: Bloqueado mientras el contrato está pausado
+        pausable::when_not_paused(&env);
+
         // ✅ AUTORIZACIÓN CRÍTICA: Solo el propietario actual puede transferir
         from.require_auth();
 
         // Verificar que el certificado existe
-        let cert_key = DataKey::Certificates(certificate_id);
-        if env.storage().persistent().get::<DataKey, VerificationRecord>(&cert_key).is_none() {
+        if Self::read_certificate(&env, certificate_id).is_none() {
             return Err(ContractError::NotFound);
         }
 
+        // ✅ Solo un certificado activo puede transferirse
+        Self::require_active(&env, certificate_id)?;
+
         // Obtener el propietario actual
         let owner_key = DataKey::CertificateOwner(certificate_id);
-        let current_owner: Address = env.storage().persistent().get(&owner_key)
+        let current_owner: Address = Self::read_owner(&env, certificate_id)
             .ok_or(ContractError::NotFound)?;
 
         // ✅ VERIFICAR PROPIEDAD: 'from' debe ser el propietario actual
@@ -268,6 +1140,7 @@ impl CarbonCertifier {
 
         // Transferir la propiedad
         env.storage().persistent().set(&owner_key, &to);
+        Self::extend_persistent_ttl(&env, &owner_key);
 
         // ✅ EMITIR EVENTO: Notificar la transferencia del certificado
         CertificateTransferredEvent {
@@ -283,183 +1156,2254 @@ impl CarbonCertifier {
     /// Quema (retira) un certificado de carbono NFT
     /// 
     /// Solo el propietario actual del certificado puede quemarlo.
-    /// Quemar un certificado es el acto final de compensación de carbono.
+    /// Quemar un certificado es el acto final de compensación de carbono:
+    /// también se queman los CXO equivalentes a `co2e_kg` del propietario en el contrato de token,
+    /// de modo que la oferta de CXO coincide con el tonelaje no retirado.
+    /// 
+    /// Se emite un recibo de retiro a nombre de `beneficiary`, igual que en
+    /// `retire_partial` con todo el tonelaje vigente.
     /// 
     /// # Argumentos
     /// * `env` - El entorno del contrato
     /// * `certificate_id` - ID del certificado a quemar
+    /// * `beneficiary` - Dirección en cuyo nombre se compensa el CO2e
+    /// * `beneficiary_name` - Nombre del beneficiario que constará en el recibo
+    /// * `reason` - Motivo del retiro que constará en el recibo
+    /// 
+    /// # Retorna
+    /// `u32` - ID del recibo de retiro emitido
     /// 
     /// # Errores
+    /// * `PausableError::EnforcedPause` si el contrato está pausado
     /// * `ContractError::NotFound` si el certificado no existe
     /// * `ContractError::NotOwner` si el llamador no es el propietario
+    /// * `ContractError::CertificateRevoked` si el certificado fue revocado
+    /// * `ContractError::InvalidStatusTransition` si el certificado no está activo
+    /// * `ContractError::TokenBurnFailed` si no se pudieron quemar los CXO del propietario
     /// 
     /// # Emite
     /// * `CertificateBurnedEvent` con los datos de la quema
-    pub fn burn_certificate(env: Env, certificate_id: u32) -> Result<(), ContractError> {
+    pub fn burn_certificate(
+        env: Env,
+        certificate_id: u32,
+        beneficiary: Address,
+        beneficiary_name: String,
+        reason: String,
+    ) -> Result<u32, ContractError> {
+        // ✅ PAUSA: Bloqueado mientras el contrato está pausado
+        pausable::when_not_paused(&env);
+
         // Verificar que el certificado existe y obtener el record
-        let cert_key = DataKey::Certificates(certificate_id);
-        let record: VerificationRecord = env.storage().persistent().get(&cert_key)
+        let record: VerificationRecord = Self::read_certificate(&env, certificate_id)
             .ok_or(ContractError::NotFound)?;
 
         // Obtener el propietario actual
-        let owner_key = DataKey::CertificateOwner(certificate_id);
-        let owner: Address = env.storage().persistent().get(&owner_key)
+        let owner: Address = Self::read_owner(&env, certificate_id)
             .ok_or(ContractError::NotFound)?;
 
         // ✅ AUTORIZACIÓN: Solo el propietario puede quemar
         owner.require_auth();
 
-        // Guardar el CO2e antes de eliminar el record
-        let co2e_tons = record.co2e_tons;
-
-        // ✅ ELIMINAR PROPIETARIO del Persistent Storage
-        env.storage().persistent().remove(&owner_key);
+        // ✅ TRANSICIÓN: Active -> Retired (el estado se conserva tras eliminar el record)
+        Self::transition_status(&env, certificate_id, CertificateStatus::Retired)?;
 
-        // ✅ ELIMINAR VERIFICATION RECORD del Persistent Storage
-        env.storage().persistent().remove(&cert_key);
+        let co2e_kg = record.co2e_kg;
 
-        // ✅ ELIMINAR de FarmerCertList (índice del agricultor)
-        Self::remove_from_index(&env, &record.farmer_address, certificate_id, true);
+        // ✅ EMITIR RECIBO permanente de retiro, mientras se conoce lo retirado parcialmente
+        let receipt_id = Self::issue_receipt(&env, certificate_id, &record, &owner, co2e_kg, RetirementClaim {
+            beneficiary,
+            beneficiary_name,
+            reason,
+        });
 
-        // ✅ ELIMINAR de VerifierCertList (índice del verificador)
-        Self::remove_from_index(&env, &record.verifier_address, certificate_id, false);
+        // ✅ ELIMINAR el certificado, su propietario y sus índices
+        Self::remove_certificate(&env, certificate_id, &record);
 
         // ✅ ACTUALIZAR CONTADORES GLOBALES
-        Self::decrement_certificate_count(&env);
-        Self::subtract_co2e_from_total(&env, co2e_tons);
+        Self::subtract_co2e_from_total(&env, co2e_kg);
+        Self::add_co2e_to_retired(&env, co2e_kg);
+
+        // ✅ INVOCACIÓN CROSS-CONTRACT: Quemar los CXO equivalentes del propietario
+        // Si falla, el host revierte la eliminación del certificado y el recibo
+        Self::burn_cxo(&env, &owner, co2e_kg)?;
 
         // ✅ EMITIR EVENTO: Notificar la quema del certificado
         CertificateBurnedEvent {
             certificate_id,
             burned_by: owner,
-            co2e_tons_retired: co2e_tons,
+            co2e_kg_retired: co2e_kg,
+            receipt_id,
         }
         .publish(&env);
 
-        Ok(())
+        Ok(receipt_id)
     }
 
-    /// Establece el ID del contrato de token fungible CARBONXO
+    /// Retira parcialmente el CO2e de un certificado de carbono NFT
     /// 
-    /// Solo puede ser invocado por el administrador del contrato.
+    /// Solo el propietario actual puede retirar. Reduce el tonelaje vigente del
+    /// certificado en `co2e_kg`, quema los CXO equivalentes del propietario y
+    /// registra lo retirado. El certificado sigue vivo hasta que su tonelaje
+    /// vigente llega a cero, momento en que se elimina como en `burn_certificate`.
     /// 
     /// # Argumentos
     /// * `env` - El entorno del contrato
-    /// * `admin` - Dirección del administrador
-    /// * `token_id` - Address del contrato CarbonToken
+    /// * `certificate_id` - ID del certificado
+    /// * `co2e_kg` - Kilogramos de CO2e a retirar
+    /// * `beneficiary` - Dirección en cuyo nombre se compensa el CO2e
+    /// * `beneficiary_name` - Nombre del beneficiario que constará en el recibo
+    /// * `reason` - Motivo del retiro que constará en el recibo
+    /// 
+    /// # Retorna
+    /// `u32` - ID del recibo de retiro emitido
     /// 
     /// # Errores
-    /// * `ContractError::NotAuthorized` si el llamador no es el admin
-    pub fn set_token_contract_id(
+    /// * `PausableError::EnforcedPause` si el contrato está pausado
+    /// * `ContractError::NotFound` si el certificado no existe
+    /// * `ContractError::InvalidInput` si `co2e_kg` es 0 o excede el tonelaje vigente
+    /// * `ContractError::CertificateRevoked` si el certificado fue revocado
+    /// * `ContractError::InvalidStatusTransition` si el certificado no está activo
+    /// * `ContractError::TokenBurnFailed` si no se pudieron quemar los CXO del propietario
+    /// 
+    /// # Emite
+    /// * `CertificateRetiredEvent` con lo retirado y lo restante
+    pub fn retire_partial(
         env: Env,
-        admin: Address,
-        token_id: Address,
-    ) -> Result<(), ContractError> {
-        // ✅ AUTORIZACIÓN: Solo el admin puede configurar
-        admin.require_auth();
+        certificate_id: u32,
+        co2e_kg: u128,
+        beneficiary: Address,
+        beneficiary_name: String,
+        reason: String,
+    ) -> Result<u32, ContractError> {
+        // ✅ PAUSA: Bloqueado mientras el contrato está pausado
+        pausable::when_not_paused(&env);
 
-        // Obtener el admin guardado
-        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin)
-            .ok_or(ContractError::NotAuthorized)?;
+        let cert_key = DataKey::Certificates(certificate_id);
+        let mut record: VerificationRecord = Self::read_certificate(&env, certificate_id)
+            .ok_or(ContractError::NotFound)?;
 
-        // Verificar que el admin coincida
-        if stored_admin != admin {
-            return Err(ContractError::NotAuthorized);
+        let owner: Address = Self::read_owner(&env, certificate_id)
+            .ok_or(ContractError::NotFound)?;
+
+        // ✅ AUTORIZACIÓN: Solo el propietario puede retirar
+        owner.require_auth();
+
+        // ✅ Solo un certificado activo puede retirarse
+        Self::require_active(&env, certificate_id)?;
+
+        // ✅ VALIDACIÓN: La cantidad debe ser positiva y no exceder lo vigente
+        if co2e_kg == 0 || co2e_kg > record.co2e_kg {
+            return Err(ContractError::InvalidInput);
         }
 
-        // Guardar el ID del contrato de token
-        env.storage().instance().set(&DataKey::TokenContractId, &token_id);
+        // ✅ EMITIR RECIBO permanente de retiro con los datos originales
+        let receipt_id = Self::issue_receipt(&env, certificate_id, &record, &owner, co2e_kg, RetirementClaim {
+            beneficiary: beneficiary.clone(),
+            beneficiary_name,
+            reason,
+        });
 
-        Ok(())
+        let remaining = record.co2e_kg - co2e_kg;
+        if remaining == 0 {
+            // ✅ Retiro total: Active -> Retired y el certificado deja de existir
+            Self::transition_status(&env, certificate_id, CertificateStatus::Retired)?;
+            Self::remove_certificate(&env, certificate_id, &record);
+        } else {
+            // ✅ Retiro parcial: reducir el tonelaje vigente y acumular lo retirado
+            record.co2e_kg = remaining;
+            env.storage().persistent().set(&cert_key, &record);
+            Self::extend_persistent_ttl(&env, &cert_key);
+
+            Self::add_certificate_retired(&env, certificate_id, co2e_kg);
+        }
+
+        // ✅ ACTUALIZAR CONTADORES GLOBALES
+        Self::subtract_co2e_from_total(&env, co2e_kg);
+        Self::add_co2e_to_retired(&env, co2e_kg);
+
+        // ✅ INVOCACIÓN CROSS-CONTRACT: Quemar los CXO equivalentes del propietario
+        Self::burn_cxo(&env, &owner, co2e_kg)?;
+
+        // ✅ EMITIR EVENTO: Notificar el retiro parcial
+        CertificateRetiredEvent {
+            certificate_id,
+            retired_by: owner,
+            beneficiary,
+            co2e_kg_retired: co2e_kg,
+            co2e_kg_remaining: remaining,
+            receipt_id,
+        }
+        .publish(&env);
+
+        Ok(receipt_id)
     }
 
-    /// Acuña un nuevo certificado de carbono NFT
+    /// Obtiene un recibo de retiro por su ID
     /// 
-    /// Solo puede ser invocado por la dirección del verificador autorizado.
-    /// Almacena el certificado en Persistent Storage para garantizar su longevidad.
+    /// # Errores
+    /// * `ContractError::NotFound` si el recibo no existe
+    pub fn get_retirement_receipt(env: Env, receipt_id: u32) -> Result<RetirementReceipt, ContractError> {
+        Self::read_persistent(&env, &DataKey::RetirementReceipt(receipt_id))
+            .ok_or(ContractError::NotFound)
+    }
+
+    /// Lista los IDs de recibos de retiro emitidos a favor de un beneficiario
     /// 
     /// # Argumentos
     /// * `env` - El entorno del contrato
-    /// * `certificate_id` - ID único del certificado (u32)
-    /// * `record` - Los datos completos del certificado de verificación
+    /// * `beneficiary` - Dirección del beneficiario
+    /// * `offset` - Número de elementos a saltar
+    /// * `limit` - Número máximo de elementos a retornar
     /// 
     /// # Retorna
-    /// `()` - Éxito
+    /// `(Vec<u32>, u32)` - Tupla con (IDs de recibos paginados, total de recibos)
+    pub fn list_receipts_by_beneficiary(
+        env: Env,
+        beneficiary: Address,
+        offset: u32,
+        limit: u32,
+    ) -> (Vec<u32>, u32) {
+        let all_receipts = Self::read_cert_list(&env, &DataKey::BeneficiaryReceiptList(beneficiary));
+        Self::paginate_cert_list(&env, &all_receipts, offset, limit)
+    }
+
+    /// Obtiene el total de recibos de retiro emitidos
+    pub fn get_total_receipts(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::TotalReceipts).unwrap_or(0)
+    }
+
+    /// Divide un certificado en certificados hijos cuyo tonelaje suma el del padre
+    /// 
+    /// Solo el propietario actual puede dividir. Los hijos reciben IDs nuevos,
+    /// conservan verificador, agricultor y `metadata_hash` del padre, se reparten
+    /// sus hectáreas en proporción a su CO2e (el último recibe el resto, de modo
+    /// que suman las del padre), quedan a nombre del mismo propietario y enlazados al padre mediante
+    /// `get_certificate_parent`. El padre se elimina y queda en estado `Split`,
+    /// por lo que su ID no puede volver a acuñarse; el primer hijo hereda su
+    /// (parcela, zafra) y lo ya retirado parcialmente. El total de CO2e y los CXO
+    /// en circulación no cambian.
+    /// 
+    /// # Argumentos
+    /// * `env` - El entorno del contrato
+    /// * `certificate_id` - ID del certificado a dividir
+    /// * `amounts` - Kilogramos de CO2e de cada hijo (al menos dos, todos > 0)
+    /// 
+    /// # Retorna
+    /// `Vec<u32>` - IDs de los certificados hijos, en el orden de `amounts`
     /// 
     /// # Errores
-    /// * `ContractError::AlreadyExists` si el certificado ya existe
-    /// * `ContractError::InvalidInput` si los datos son inválidos (hectares o CO2e <= 0)
+    /// * `PausableError::EnforcedPause` si el contrato está pausado
+    /// * `ContractError::NotFound` si el certificado no existe
+    /// * `ContractError::CertificateRevoked` si el certificado fue revocado
+    /// * `ContractError::InvalidStatusTransition` si el certificado no está activo
+    /// * `ContractError::InvalidInput` si hay menos de dos partes, alguna es 0
+    ///   o la suma no coincide con el tonelaje del padre
+    /// * `ContractError::CertificateIdsExhausted` si no quedan IDs libres
     /// 
-    /// # Autorización
-    /// Requiere autenticación de `record.verifier_address`
-    pub fn mint_certificate(
+    /// # Emite
+    /// * `CertificateSplitEvent` con los IDs de los hijos
+    pub fn split_certificate(
         env: Env,
         certificate_id: u32,
-        record: VerificationRecord,
+        amounts: Vec<u128>,
+    ) -> Result<Vec<u32>, ContractError> {
+        // ✅ PAUSA: Bloqueado mientras el contrato está pausado
+        pausable::when_not_paused(&env);
+
+        let record: VerificationRecord = Self::read_certificate(&env, certificate_id)
+            .ok_or(ContractError::NotFound)?;
+        let owner: Address = Self::read_owner(&env, certificate_id)
+            .ok_or(ContractError::NotFound)?;
+
+        // ✅ AUTORIZACIÓN: Solo el propietario puede dividir
+        owner.require_auth();
+
+        // ✅ Solo un certificado activo puede dividirse
+        Self::require_active(&env, certificate_id)?;
+
+        // ✅ VALIDACIÓN: Al menos dos partes positivas que sumen el tonelaje del padre
+        if amounts.len() < 2 {
+            return Err(ContractError::InvalidInput);
+        }
+        let mut total: u128 = 0;
+        for amount in amounts.iter() {
+            if amount == 0 {
+                return Err(ContractError::InvalidInput);
+            }
+            total = total.checked_add(amount).ok_or(ContractError::InvalidInput)?;
+        }
+        if total != record.co2e_kg {
+            return Err(ContractError::InvalidInput);
+        }
+
+        // ✅ ELIMINAR el padre (queda en estado `Split`) y CREAR los hijos activos con la misma procedencia
+        let retired = Self::get_certificate_retired(env.clone(), certificate_id);
+        Self::transition_status(&env, certificate_id, CertificateStatus::Split)?;
+        Self::remove_certificate(&env, certificate_id, &record);
+
+        let mut child_ids: Vec<u32> = Vec::new(&env);
+        let mut hectares_left = record.hectares_not_burned;
+        for (i, amount) in amounts.iter().enumerate() {
+            // ✅ PROPORCIÓN: Hectáreas según la parte del CO2e; el último hijo recibe el resto
+            let hectares = if i + 1 == amounts.len() as usize {
+                hectares_left
+            } else {
+                let share = (record.hectares_not_burned as u128).checked_mul(amount)
+                    .ok_or(ContractError::InvalidInput)? / total;
+                share as u32
+            };
+            hectares_left -= hectares;
+
+            let child_id = Self::allocate_certificate_id(&env)?;
+            let child = VerificationRecord {
+                co2e_kg: amount,
+                hectares_not_burned: hectares,
+                ..record.clone()
+            };
+            Self::insert_certificate(&env, child_id, &child, &owner, CertificateStatus::Active);
+            Self::write_persistent(&env, &DataKey::CertificateParent(child_id), &certificate_id);
+            child_ids.push_back(child_id);
+        }
+
+        // ✅ LINAJE: El primer hijo hereda la (parcela, zafra) y lo ya retirado del padre
+        let heir_id = child_ids.get_unchecked(0);
+        Self::repoint_parcel_season(&env, &record, &Vec::from_array(&env, [certificate_id]), heir_id);
+        Self::add_certificate_retired(&env, heir_id, retired);
+
+        // ✅ EMITIR EVENTO: Notificar la división
+        CertificateSplitEvent {
+            certificate_id,
+            child_ids: child_ids.clone(),
+        }
+        .publish(&env);
+
+        Ok(child_ids)
+    }
+
+    /// Fusiona certificados con la misma procedencia en un nuevo certificado
+    /// 
+    /// Todos los certificados deben pertenecer al mismo propietario y compartir
+    /// verificador, agricultor, `metadata_hash`, región y zafra. Los fusionados
+    /// se eliminan y quedan en estado `Merged`. El certificado resultante
+    /// recibe un ID nuevo con el tonelaje y las hectáreas sumados; si todos provienen del mismo
+    /// padre, conserva ese enlace, y hereda la (parcela, zafra) y lo ya retirado
+    /// parcialmente de los fusionados. El total de CO2e y los CXO no cambian.
+    /// 
+    /// # Argumentos
+    /// * `env` - El entorno del contrato
+    /// * `certificate_ids` - IDs de los certificados a fusionar (al menos dos, sin repetir)
+    /// 
+    /// # Retorna
+    /// `u32` - ID del certificado resultante
+    /// 
+    /// # Errores
+    /// * `PausableError::EnforcedPause` si el contrato está pausado
+    /// * `ContractError::NotFound` si algún certificado no existe
+    /// * `ContractError::NotOwner` si los certificados tienen distintos propietarios
+    /// * `ContractError::CertificateRevoked` si algún certificado fue revocado
+    /// * `ContractError::InvalidStatusTransition` si algún certificado no está activo
+    /// * `ContractError::InvalidInput` si hay menos de dos IDs, IDs repetidos o
+    ///   procedencias distintas
+    /// * `ContractError::CertificateIdsExhausted` si no quedan IDs libres
+    /// 
+    /// # Emite
+    /// * `CertificatesMergedEvent` con los IDs fusionados y el resultante
+    pub fn merge_certificates(env: Env, certificate_ids: Vec<u32>) -> Result<u32, ContractError> {
+        // ✅ PAUSA: Bloqueado mientras el contrato está pausado
+        pausable::when_not_paused(&env);
+
+        if certificate_ids.len() < 2 {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let first_id = certificate_ids.get_unchecked(0);
+        let mut merged: VerificationRecord = Self::read_certificate(&env, first_id)
+            .ok_or(ContractError::NotFound)?;
+        let owner: Address = Self::read_owner(&env, first_id)
+            .ok_or(ContractError::NotFound)?;
+
+        // ✅ AUTORIZACIÓN: Solo el propietario puede fusionar
+        owner.require_auth();
+
+        let mut parent: Option<u32> = Self::read_persistent(&env, &DataKey::CertificateParent(first_id));
+        merged.co2e_kg = 0;
+        merged.hectares_not_burned = 0;
+        let mut retired: u128 = 0;
+
+        for (i, id) in certificate_ids.iter().enumerate() {
+            // ✅ VALIDACIÓN: Sin IDs repetidos
+            if certificate_ids.iter().skip(i + 1).any(|other| other == id) {
+                return Err(ContractError::InvalidInput);
+            }
+
+            let record: VerificationRecord = Self::read_certificate(&env, id)
+                .ok_or(ContractError::NotFound)?;
+            let record_owner: Address = Self::read_owner(&env, id)
+                .ok_or(ContractError::NotFound)?;
+            if record_owner != owner {
+                return Err(ContractError::NotOwner);
+            }
+            Self::require_active(&env, id)?;
+
+            // ✅ VALIDACIÓN: Misma procedencia
+            if record.verifier_address != merged.verifier_address
+                || record.farmer_address != merged.farmer_address
+                || record.metadata_hash != merged.metadata_hash
+                || record.region != merged.region
+                || record.season != merged.season
+                || record.methodology_version != merged.methodology_version
+                || record.parcel_id != merged.parcel_id
+                || record.vintage != merged.vintage
+                || record.project_id != merged.project_id
+            {
+                return Err(ContractError::InvalidInput);
+            }
+
+            let record_parent: Option<u32> = Self::read_persistent(&env, &DataKey::CertificateParent(id));
+            if record_parent != parent {
+                parent = None;
+            }
+
+            merged.co2e_kg = merged.co2e_kg.checked_add(record.co2e_kg)
+                .ok_or(ContractError::InvalidInput)?;
+            merged.hectares_not_burned = merged.hectares_not_burned.checked_add(record.hectares_not_burned)
+                .ok_or(ContractError::InvalidInput)?;
+            retired += Self::get_certificate_retired(env.clone(), id);
+            Self::transition_status(&env, id, CertificateStatus::Merged)?;
+            Self::remove_certificate(&env, id, &record);
+        }
+
+        // ✅ CREAR el certificado resultante
+        let merged_id = Self::allocate_certificate_id(&env)?;
+        Self::insert_certificate(&env, merged_id, &merged, &owner, CertificateStatus::Active);
+        if let Some(parent_id) = parent {
+            Self::write_persistent(&env, &DataKey::CertificateParent(merged_id), &parent_id);
+        }
+
+        // ✅ LINAJE: El resultante hereda la (parcela, zafra) y lo ya retirado de los fusionados
+        Self::repoint_parcel_season(&env, &merged, &certificate_ids, merged_id);
+        Self::add_certificate_retired(&env, merged_id, retired);
+
+        // ✅ EMITIR EVENTO: Notificar la fusión
+        CertificatesMergedEvent {
+            certificate_ids,
+            merged_id,
+        }
+        .publish(&env);
+
+        Ok(merged_id)
+    }
+
+    /// Revoca un certificado fraudulento o inválido
+    /// 
+    /// Puede invocarlo el verificador que emitió el certificado, mientras siga
+    /// activo y con el rol `verifier`, o el rol `revoker`. El certificado no se elimina: queda marcado como revocado, bloqueado para
+    /// transferencia y retiro, y su CO2e vigente se excluye de `TotalCO2e`.
+    /// Los CXO equivalentes se recuperan (clawback) del agricultor, a quien se
+    /// acuñaron, hasta donde alcance su balance; el resto queda registrado como
+    /// no recuperado. Transferir el NFT no mueve los CXO, por lo que el
+    /// propietario actual no se ve afectado.
+    /// 
+    /// # Argumentos
+    /// * `env` - El entorno del contrato
+    /// * `caller` - Verificador emisor o dirección con el rol `revoker`
+    /// * `certificate_id` - ID del certificado a revocar
+    /// * `reason_hash` - Hash del documento que justifica la revocación
+    /// 
+    /// # Errores
+    /// * `ContractError::NotFound` si el certificado no existe
+    /// * `ContractError::NotAuthorized` si `caller` no es el verificador emisor activo ni tiene el rol `revoker`
+    /// * `ContractError::CertificateRevoked` si el certificado ya fue revocado
+    /// * `ContractError::InvalidStatusTransition` si el certificado está retirado o expirado
+    /// * `ContractError::TokenBurnFailed` si no se pudieron recuperar los CXO
+    /// 
+    /// # Emite
+    /// * `CertificateRevokedEvent` con los datos de la revocación
+    pub fn revoke_certificate(
+        env: Env,
+        caller: Address,
+        certificate_id: u32,
+        reason_hash: BytesN<32>,
     ) -> Result<(), ContractError> {
-        // ✅ AUTORIZACIÓN CRÍTICA: Solo el verificador autorizado puede acuñar certificados
-        record.verifier_address.require_auth();
+        // ✅ AUTORIZACIÓN: El llamador debe firmar
+        caller.require_auth();
+
+        let record: VerificationRecord = Self::read_certificate(&env, certificate_id)
+            .ok_or(ContractError::NotFound)?;
+
+        // ✅ VERIFICAR ROL: Solo el verificador emisor aún activo o el rol `revoker`
+        if access_control::has_role(&env, &caller, &Symbol::new(&env, REVOKER_ROLE)).is_none()
+            && (caller != record.verifier_address || Self::require_active_verifier(&env, &caller).is_err())
+        {
+            return Err(ContractError::NotAuthorized);
+        }
+
+        // ✅ TRANSICIÓN: Pending | Active -> Revoked
+        let was_pending = Self::read_status(&env, certificate_id) == Some(CertificateStatus::Pending);
+        Self::transition_status(&env, certificate_id, CertificateStatus::Revoked)?;
+
+        if was_pending {
+            // Un certificado pendiente aún no sumó CO2e ni acuñó CXO
+            env.storage().persistent().remove(&DataKey::ChallengeDeadline(certificate_id));
+            env.storage().persistent().remove(&DataKey::Challenge(certificate_id));
+            Self::record_revocation(&env, certificate_id, caller, reason_hash, 0, (0, 0));
+            return Ok(());
+        }
+
+        // ✅ EXCLUIR del total de CO2e
+        Self::subtract_co2e_from_total(&env, record.co2e_kg);
+
+        // ✅ INVOCACIÓN CROSS-CONTRACT: Recuperar los CXO del agricultor al que se acuñaron
+        let cxo = Self::clawback_cxo(&env, &record.farmer_address, record.co2e_kg)?;
+
+        // ✅ MARCAR como revocado y EMITIR EVENTO
+        Self::record_revocation(&env, certificate_id, caller, reason_hash, record.co2e_kg, cxo);
+
+        Ok(())
+    }
+
+    /// Indica si un certificado fue revocado
+    pub fn is_certificate_revoked(env: Env, certificate_id: u32) -> bool {
+        Self::read_status(&env, certificate_id) == Some(CertificateStatus::Revoked)
+    }
+
+    /// Expira un certificado activo
+    /// 
+    /// Solo puede ser invocado por el rol `revoker`. El certificado se conserva,
+    /// deja de poder transferirse o retirarse y su CO2e vigente se excluye de
+    /// `TotalCO2e`. Como en `revoke_certificate`, los CXO equivalentes se
+    /// recuperan del agricultor hasta donde alcance su balance.
+    /// 
+    /// # Errores
+    /// * `AccessControlError::Unauthorized` si el llamador no tiene el rol `revoker`
+    /// * `ContractError::NotFound` si el certificado no existe
+    /// * `ContractError::InvalidStatusTransition` si el certificado no está activo
+    /// * `ContractError::TokenBurnFailed` si la recuperación de CXO falla
+    /// 
+    /// # Emite
+    /// * `CertificateExpiredEvent`
+    pub fn expire_certificate(env: Env, caller: Address, certificate_id: u32) -> Result<(), ContractError> {
+        Self::ensure_role(&env, &caller, REVOKER_ROLE);
+
+        let record: VerificationRecord = Self::read_certificate(&env, certificate_id)
+            .ok_or(ContractError::NotFound)?;
+
+        // ✅ TRANSICIÓN: Active -> Expired
+        Self::transition_status(&env, certificate_id, CertificateStatus::Expired)?;
+        Self::subtract_co2e_from_total(&env, record.co2e_kg);
+
+        // ✅ INVOCACIÓN CROSS-CONTRACT: Los CXO siguen al CO2e fuera de circulación
+        let (cxo_clawed_back, _) = Self::clawback_cxo(&env, &record.farmer_address, record.co2e_kg)?;
+
+        CertificateExpiredEvent {
+            certificate_id,
+            co2e_kg_expired: record.co2e_kg,
+            cxo_clawed_back,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Obtiene el estado del ciclo de vida de un certificado
+    /// 
+    /// Los certificados retirados, divididos o fusionados conservan su estado
+    /// aunque su record se haya eliminado.
+    /// 
+    /// # Errores
+    /// * `ContractError::NotFound` si el certificado nunca existió
+    pub fn get_certificate_status(env: Env, certificate_id: u32) -> Result<CertificateStatus, ContractError> {
+        Self::read_status(&env, certificate_id).ok_or(ContractError::NotFound)
+    }
+
+    /// Obtiene el registro de revocación de un certificado
+    /// 
+    /// # Errores
+    /// * `ContractError::NotFound` si el certificado no fue revocado
+    pub fn get_revocation(env: Env, certificate_id: u32) -> Result<RevocationRecord, ContractError> {
+        Self::read_persistent(&env, &DataKey::Revocation(certificate_id))
+            .ok_or(ContractError::NotFound)
+    }
+
+    /// Obtiene el certificado padre de un certificado creado por división o fusión
+    /// 
+    /// # Retorna
+    /// `Option<u32>` - ID del padre, o `None` si el certificado fue acuñado directamente
+    pub fn get_certificate_parent(env: Env, certificate_id: u32) -> Option<u32> {
+        Self::read_persistent(&env, &DataKey::CertificateParent(certificate_id))
+    }
+
+    /// Obtiene los kilogramos de CO2e ya retirados parcialmente de un certificado vigente
+    /// 
+    /// # Retorna
+    /// `u128` - Kilogramos retirados (0 si nunca se retiró parcialmente)
+    pub fn get_certificate_retired(env: Env, certificate_id: u32) -> u128 {
+        env.storage().persistent()
+            .get(&DataKey::CertificateRetired(certificate_id))
+            .unwrap_or(0)
+    }
+
+    /// Obtiene el total de CO2e retirado en kilogramos (quemas y retiros parciales)
+    /// 
+    /// # Retorna
+    /// `u128` - El total de kilogramos de CO2e retirados
+    pub fn get_total_retired_co2e(env: Env) -> u128 {
+        env.storage().instance().get(&DataKey::TotalRetired).unwrap_or(0)
+    }
+
+    /// Establece el ID del contrato de token fungible CARBONXO
+    /// 
+    /// Solo puede ser invocado por una dirección con el rol `config_manager`.
+    /// 
+    /// # Argumentos
+    /// * `env` - El entorno del contrato
+    /// * `caller` - Dirección con el rol `config_manager`
+    /// * `token_id` - Address del contrato CarbonToken
+    /// 
+    /// # Errores
+    /// * `AccessControlError::Unauthorized` si el llamador no tiene el rol `config_manager`
+    pub fn set_token_contract_id(
+        env: Env,
+        caller: Address,
+        token_id: Address,
+    ) -> Result<(), ContractError> {
+        Self::ensure_role(&env, &caller, CONFIG_MANAGER_ROLE);
+
+        // Guardar el ID del contrato de token
+        env.storage().instance().set(&DataKey::TokenContractId, &token_id);
+
+        Ok(())
+    }
+
+    /// Registra un nuevo verificador acreditado y le otorga el rol `verifier`
+    /// 
+    /// Solo puede ser invocado por una dirección con el rol `registry_manager`.
+    /// 
+    /// # Argumentos
+    /// * `env` - El entorno del contrato
+    /// * `caller` - Dirección con el rol `registry_manager`
+    /// * `verifier` - Dirección del verificador a registrar
+    /// * `organization_name` - Nombre de la organización acreditada
+    /// * `accreditation_hash` - Hash SHA-256 del documento de acreditación
+    /// 
+    /// # Errores
+    /// * `AccessControlError::Unauthorized` si el llamador no tiene el rol `registry_manager`
+    /// * `ContractError::AlreadyExists` si el verificador ya está registrado
+    /// 
+    /// # Emite
+    /// * `VerifierAddedEvent` con los datos del verificador
+    pub fn add_verifier(
+        env: Env,
+        caller: Address,
+        verifier: Address,
+        organization_name: String,
+        accreditation_hash: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        Self::ensure_role(&env, &caller, REGISTRY_MANAGER_ROLE);
+
+        let key = DataKey::Verifier(verifier.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(ContractError::AlreadyExists);
+        }
+
+        let info = VerifierInfo {
+            organization_name: organization_name.clone(),
+            accreditation_hash,
+            status: VerifierStatus::Active,
+            registered_at: env.ledger().timestamp(),
+        };
+        Self::write_persistent(&env, &key, &info);
+
+        // ✅ ROLES: El registro otorga el rol `verifier`
+        access_control::grant_role_no_auth(&env, &caller, &verifier, &Symbol::new(&env, VERIFIER_ROLE));
+
+        // ✅ EMITIR EVENTO
+        VerifierAddedEvent {
+            verifier,
+            organization_name,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Suspende a un verificador registrado
+    /// 
+    /// Un verificador suspendido conserva su registro pero no puede acuñar certificados.
+    /// 
+    /// # Errores
+    /// * `AccessControlError::Unauthorized` si el llamador no tiene el rol `registry_manager`
+    /// * `ContractError::NotFound` si el verificador no está registrado
+    /// 
+    /// # Emite
+    /// * `VerifierStatusChangedEvent` con el estado `Suspended`
+    pub fn suspend_verifier(env: Env, caller: Address, verifier: Address) -> Result<(), ContractError> {
+        Self::ensure_role(&env, &caller, REGISTRY_MANAGER_ROLE);
+        Self::set_verifier_status(&env, verifier, VerifierStatus::Suspended)
+    }
+
+    /// Reactiva a un verificador previamente suspendido
+    /// 
+    /// # Errores
+    /// * `AccessControlError::Unauthorized` si el llamador no tiene el rol `registry_manager`
+    /// * `ContractError::NotFound` si el verificador no está registrado
+    /// 
+    /// # Emite
+    /// * `VerifierStatusChangedEvent` con el estado `Active`
+    pub fn reactivate_verifier(env: Env, caller: Address, verifier: Address) -> Result<(), ContractError> {
+        Self::ensure_role(&env, &caller, REGISTRY_MANAGER_ROLE);
+        Self::set_verifier_status(&env, verifier, VerifierStatus::Active)
+    }
+
+    /// Elimina a un verificador del registro
+    /// 
+    /// Los certificados ya acuñados por el verificador no se ven afectados; pierde
+    /// el rol `verifier`.
+    /// 
+    /// # Errores
+    /// * `AccessControlError::Unauthorized` si el llamador no tiene el rol `registry_manager`
+    /// * `ContractError::NotFound` si el verificador no está registrado
+    /// 
+    /// # Emite
+    /// * `VerifierRemovedEvent`
+    pub fn remove_verifier(env: Env, caller: Address, verifier: Address) -> Result<(), ContractError> {
+        Self::ensure_role(&env, &caller, REGISTRY_MANAGER_ROLE);
+
+        let key = DataKey::Verifier(verifier.clone());
+        if !env.storage().persistent().has(&key) {
+            return Err(ContractError::NotFound);
+        }
+        env.storage().persistent().remove(&key);
+
+        // ✅ ROLES: Retirar el rol `verifier` junto con el registro
+        if access_control::has_role(&env, &verifier, &Symbol::new(&env, VERIFIER_ROLE)).is_some() {
+            access_control::revoke_role_no_auth(&env, &caller, &verifier, &Symbol::new(&env, VERIFIER_ROLE));
+        }
+
+        // ✅ EMITIR EVENTO
+        VerifierRemovedEvent { verifier }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Obtiene los metadatos de un verificador registrado
+    /// 
+    /// # Errores
+    /// * `ContractError::NotFound` si el verificador no está registrado
+    pub fn get_verifier(env: Env, verifier: Address) -> Result<VerifierInfo, ContractError> {
+        Self::read_persistent(&env, &DataKey::Verifier(verifier))
+            .ok_or(ContractError::NotFound)
+    }
+
+    /// Indica si una dirección es un verificador registrado y activo
+    pub fn is_active_verifier(env: Env, verifier: Address) -> bool {
+        Self::require_active_verifier(&env, &verifier).is_ok()
+    }
+
+    /// Acuña un nuevo certificado de carbono NFT
+    /// 
+    /// Solo puede ser invocado por un verificador registrado y activo.
+    /// Almacena el certificado en Persistent Storage para garantizar su longevidad.
+    /// 
+    /// # Argumentos
+    /// * `env` - El entorno del contrato
+    /// * `certificate_id` - ID único del certificado (u32)
+    /// * `record` - Los datos completos del certificado de verificación
+    /// 
+    /// # Retorna
+    /// `()` - Éxito
+    /// 
+    /// # Errores
+    /// * `PausableError::EnforcedPause` si el contrato está pausado
+    /// * `ContractError::AlreadyExists` si el certificado o una propuesta vigente ya existe
+    /// * `ContractError::InvalidInput` si los datos son inválidos (hectares o CO2e <= 0)
+    /// * `ContractError::EmissionFactorNotSet` si no hay metodología registrada ni factor de emisión para la región y zafra
+    /// * `ContractError::Co2eExceedsEstimate` si el CO2e excede `estimate_co2e`
+    /// * `ContractError::MethodologyVersionMismatch` si el record no referencia la metodología vigente
+    /// * `ContractError::ParcelNotFound` / `ParcelFarmerMismatch` si la parcela no existe o es de otro agricultor
+    /// * `ContractError::HectaresExceedParcelArea` si las hectáreas exceden la superficie de la parcela
+    /// * `ContractError::ParcelSeasonAlreadyCertified` si la parcela ya tiene certificado en la zafra
+    /// * `ContractError::VerifierNotAuthorized` si el verificador no está registrado o está suspendido
+    /// * `ContractError::QuorumRequired` si el tonelaje exige aprobación por quórum
+    /// * `ContractError::TokenMintFailed` si no se pudieron acuñar los tokens CXO
+    /// 
+    /// # Autorización
+    /// Requiere autenticación de `record.verifier_address`
+    pub fn mint_certificate(
+        env: Env,
+        certificate_id: u32,
+        record: VerificationRecord,
+    ) -> Result<(), ContractError> {
+        // ✅ PAUSA: Bloqueado mientras el contrato está pausado
+        pausable::when_not_paused(&env);
+
+        // ✅ AUTORIZACIÓN CRÍTICA: Solo el verificador autorizado puede acuñar certificados
+        record.verifier_address.require_auth();
+
+        // ✅ REGISTRO: El verificador debe estar registrado y activo
+        Self::require_active_verifier(&env, &record.verifier_address)?;
+
+        // ✅ VALIDACIÓN DE DATOS: Verificar que los datos de entrada sean válidos
+        Self::validate_new_certificate(&env, certificate_id, &record)?;
+
+        // ✅ QUÓRUM: Los certificados de alto valor requieren varias firmas
+        let config = Self::get_quorum_config(env.clone());
+        if config.required_approvals > 1 && record.co2e_kg >= config.threshold_kg {
+            return Err(ContractError::QuorumRequired);
+        }
+
+        Self::issue_certificate(&env, certificate_id, record)
+    }
+
+    /// Acuña un nuevo certificado con el siguiente ID de la secuencia on-chain
+    /// 
+    /// Alternativa a `mint_certificate` que evita coordinar IDs fuera de la
+    /// cadena; el ID explícito se conserva para migraciones.
+    /// 
+    /// # Argumentos
+    /// * `env` - El entorno del contrato
+    /// * `record` - Los datos completos del certificado de verificación
+    /// 
+    /// # Retorna
+    /// El ID asignado al certificado
+    /// 
+    /// # Errores
+    /// * `ContractError::CertificateIdsExhausted` si no quedan IDs libres
+    /// * Los mismos que `mint_certificate`, salvo `AlreadyExists`
+    /// 
+    /// # Autorización
+    /// Requiere autenticación de `record.verifier_address`
+    pub fn mint_next_certificate(env: Env, record: VerificationRecord) -> Result<u32, ContractError> {
+        let certificate_id = Self::allocate_certificate_id(&env)?;
+        Self::mint_certificate(env, certificate_id, record)?;
+        Ok(certificate_id)
+    }
+
+    /// Acuña un lote de certificados al cierre de la zafra
+    /// 
+    /// Todo o nada: si un elemento falla, no se acuña ninguno. Cada verificador
+    /// firma una sola vez, los contadores e índices se actualizan una vez por
+    /// lote y los CXO se acuñan una vez por agricultor.
+    /// 
+    /// # Argumentos
+    /// * `env` - El entorno del contrato
+    /// * `items` - Pares (ID del certificado, datos de verificación)
+    /// 
+    /// # Errores
+    /// * `PausableError::EnforcedPause` si el contrato está pausado
+    /// * `ContractError::InvalidInput` si el lote está vacío
+    /// * Cualquier error de `mint_certificate` en alguno de los elementos
+    /// 
+    /// # Emite
+    /// * `CertificateMintedEvent` por cada certificado
+    /// 
+    /// # Autorización
+    /// Requiere autenticación de cada `verifier_address` distinto del lote
+    pub fn mint_certificates_batch(
+        env: Env,
+        items: Vec<(u32, VerificationRecord)>,
+    ) -> Result<(), ContractError> {
+        // ✅ PAUSA: Bloqueado mientras el contrato está pausado
+        pausable::when_not_paused(&env);
+
+        if items.is_empty() {
+            return Err(ContractError::InvalidInput);
+        }
+
+        // ✅ AUTORIZACIÓN: Una sola firma por verificador, que debe estar activo
+        let mut verifiers: Vec<Address> = Vec::new(&env);
+        for (_, record) in items.iter() {
+            if !verifiers.contains(&record.verifier_address) {
+                record.verifier_address.require_auth();
+                Self::require_active_verifier(&env, &record.verifier_address)?;
+                verifiers.push_back(record.verifier_address);
+            }
+        }
+
+        let config = Self::get_quorum_config(env.clone());
+        let challenge_window = Self::get_challenge_window(env.clone());
+        let status = if challenge_window > 0 {
+            CertificateStatus::Pending
+        } else {
+            CertificateStatus::Active
+        };
+        let deadline = env.ledger().sequence().saturating_add(challenge_window);
+        let timestamp = env.ledger().timestamp();
+
+        let mut index_updates: Map<DataKey, Vec<u32>> = Map::new(&env);
+        let mut farmer_co2e: Map<Address, u128> = Map::new(&env);
+        let mut total_co2e: u128 = 0;
+
+        for (certificate_id, record) in items.iter() {
+            // ✅ VALIDACIÓN: Cada elemento se valida contra lo ya almacenado en el lote
+            Self::validate_new_certificate(&env, certificate_id, &record)?;
+            if config.required_approvals > 1 && record.co2e_kg >= config.threshold_kg {
+                return Err(ContractError::QuorumRequired);
+            }
+            Self::claim_parcel_season(&env, certificate_id, &record)?;
+
+            Self::store_certificate(&env, certificate_id, &record, &record.farmer_address, status);
+            if status == CertificateStatus::Pending {
+                Self::write_persistent(&env, &DataKey::ChallengeDeadline(certificate_id), &deadline);
+            } else {
+                total_co2e += record.co2e_kg;
+                let farmer_total = farmer_co2e.get(record.farmer_address.clone()).unwrap_or(0);
+                farmer_co2e.set(record.farmer_address.clone(), farmer_total + record.co2e_kg);
+            }
+
+            for list_key in Self::index_keys(&record) {
+                let mut ids = index_updates.get(list_key.clone()).unwrap_or(Vec::new(&env));
+                ids.push_back(certificate_id);
+                index_updates.set(list_key, ids);
+            }
+
+            CertificateMintedEvent {
+                certificate_id,
+                farmer: record.farmer_address,
+                verifier: record.verifier_address,
+                co2e_kg_minted: record.co2e_kg,
+                timestamp,
+            }
+            .publish(&env);
+        }
+
+        // ✅ AGREGACIÓN: Una escritura por índice y por contador
+        for (list_key, ids) in index_updates.iter() {
+            Self::append_to_list(&env, &list_key, &ids);
+        }
+        Self::add_certificate_count(&env, items.len());
+        Self::add_co2e_to_total(&env, total_co2e);
+
+        // ✅ INVOCACIÓN CROSS-CONTRACT: Una acuñación de CXO por agricultor
+        for (farmer, co2e_kg) in farmer_co2e.iter() {
+            Self::mint_cxo(&env, &farmer, co2e_kg)?;
+        }
+
+        Ok(())
+    }
+
+    /// Obtiene el ID que asignará el próximo `mint_next_certificate`
+    /// 
+    /// # Errores
+    /// * `ContractError::CertificateIdsExhausted` si no quedan IDs libres
+    pub fn next_certificate_id(env: Env) -> Result<u32, ContractError> {
+        Self::find_free_certificate_id(&env)
+    }
+
+    /// Configura el quórum M-de-N de verificadores para certificados de alto valor
+    /// 
+    /// Solo puede ser invocado por una dirección con el rol `config_manager`.
+    /// 
+    /// # Argumentos
+    /// * `env` - El entorno del contrato
+    /// * `caller` - Dirección con el rol `config_manager`
+    /// * `config` - Nueva configuración de quórum
+    /// 
+    /// # Errores
+    /// * `AccessControlError::Unauthorized` si el llamador no tiene el rol `config_manager`
+    /// * `ContractError::InvalidInput` si `required_approvals` o `proposal_ttl_ledgers` es 0
+    /// 
+    /// # Emite
+    /// * `QuorumConfigUpdatedEvent`
+    pub fn set_quorum_config(env: Env, caller: Address, config: QuorumConfig) -> Result<(), ContractError> {
+        Self::ensure_role(&env, &caller, CONFIG_MANAGER_ROLE);
+
+        if config.required_approvals == 0 || config.proposal_ttl_ledgers == 0 {
+            return Err(ContractError::InvalidInput);
+        }
+
+        env.storage().instance().set(&DataKey::QuorumConfig, &config);
+
+        QuorumConfigUpdatedEvent {
+            required_approvals: config.required_approvals,
+            threshold_kg: config.threshold_kg,
+            proposal_ttl_ledgers: config.proposal_ttl_ledgers,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Obtiene la configuración de quórum vigente
+    /// 
+    /// Por defecto no se exige quórum (`required_approvals = 1`) y las propuestas
+    /// vencen en 17,280 ledgers (~1 día).
+    pub fn get_quorum_config(env: Env) -> QuorumConfig {
+        env.storage().instance().get(&DataKey::QuorumConfig).unwrap_or(QuorumConfig {
+            required_approvals: 1,
+            threshold_kg: 0,
+            proposal_ttl_ledgers: DEFAULT_PROPOSAL_TTL_LEDGERS,
+        })
+    }
+
+    /// Configura cuándo y hasta dónde se renueva el TTL del almacenamiento
+    /// 
+    /// # Argumentos
+    /// * `env` - El entorno del contrato
+    /// * `caller` - Dirección con el rol `config_manager`
+    /// * `config` - Nueva configuración de TTL
+    /// 
+    /// # Errores
+    /// * `AccessControlError::Unauthorized` si el llamador no tiene el rol `config_manager`
+    /// * `ContractError::InvalidInput` si `extend_to_ledgers` no supera a
+    ///   `threshold_ledgers` o excede el TTL máximo de la red
+    /// 
+    /// # Emite
+    /// * `TtlConfigUpdatedEvent`
+    pub fn set_ttl_config(env: Env, caller: Address, config: TtlConfig) -> Result<(), ContractError> {
+        Self::ensure_role(&env, &caller, CONFIG_MANAGER_ROLE);
+
+        if config.extend_to_ledgers <= config.threshold_ledgers
+            || config.extend_to_ledgers > env.storage().max_ttl()
+        {
+            return Err(ContractError::InvalidInput);
+        }
+
+        env.storage().instance().set(&DataKey::TtlConfig, &config);
+        Self::extend_instance_ttl(&env);
+
+        TtlConfigUpdatedEvent {
+            threshold_ledgers: config.threshold_ledgers,
+            extend_to_ledgers: config.extend_to_ledgers,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Obtiene la configuración de TTL vigente
+    /// 
+    /// Por defecto las entradas se renuevan a 2,073,600 ledgers (~120 días)
+    /// cuando les quedan menos de 518,400 (~30 días).
+    pub fn get_ttl_config(env: Env) -> TtlConfig {
+        env.storage().instance().get(&DataKey::TtlConfig).unwrap_or(TtlConfig {
+            threshold_ledgers: DEFAULT_TTL_THRESHOLD_LEDGERS,
+            extend_to_ledgers: DEFAULT_TTL_EXTEND_TO_LEDGERS,
+        })
+    }
+
+    /// Renueva el TTL de un certificado y de los datos de los que depende
+    /// 
+    /// Cubre su record, propietario, estado, retiro parcial, enlace al padre,
+    /// revocación, propuesta de quórum, ventana e impugnación pendientes, además
+    /// de su parcela, la reserva de (parcela, zafra), la versión de metodología
+    /// y el factor de emisión de esa versión para su región y zafra. Un certificado aún propuesto
+    /// se renueva a partir del record de su propuesta. No requiere autorización:
+    /// cualquiera puede pagar la renta para mantener vivo un certificado. También
+    /// renueva la instancia del contrato.
+    /// 
+    /// # Argumentos
+    /// * `env` - El entorno del contrato
+    /// * `certificate_id` - ID del certificado
+    /// 
+    /// # Errores
+    /// * `ContractError::NotFound` si no existe el certificado ni una propuesta con ese ID
+    pub fn bump_certificate(env: Env, certificate_id: u32) -> Result<(), ContractError> {
+        let record = match Self::read_certificate(&env, certificate_id) {
+            Some(record) => record,
+            None => Self::read_persistent::<CertificateProposal>(&env, &DataKey::Proposal(certificate_id))
+                .ok_or(ContractError::NotFound)?
+                .record,
+        };
+        Self::extend_certificate_ttl(&env, certificate_id);
+        for key in [
+            DataKey::CertificateRetired(certificate_id),
+            DataKey::CertificateParent(certificate_id),
+            DataKey::Revocation(certificate_id),
+            DataKey::Proposal(certificate_id),
+            DataKey::ChallengeDeadline(certificate_id),
+            DataKey::Challenge(certificate_id),
+            DataKey::Parcel(record.parcel_id),
+            DataKey::ParcelSeason(record.parcel_id, record.season),
+            DataKey::Methodology(record.methodology_version),
+            DataKey::EmissionFactor(record.methodology_version, record.region, record.season),
+        ] {
+            if env.storage().persistent().has(&key) {
+                Self::extend_persistent_ttl(&env, &key);
+            }
+        }
+        Ok(())
+    }
+
+    /// Renueva el TTL de los índices y registros de una dirección
+    /// 
+    /// Cubre sus listas como agricultor y como verificador, sus recibos como
+    /// beneficiario y su registro de verificador (los que existan), y la
+    /// instancia del contrato. No requiere autorización.
+    /// 
+    /// # Argumentos
+    /// * `env` - El entorno del contrato
+    /// * `account` - Dirección del agricultor o verificador
+    pub fn bump_account(env: Env, account: Address) {
+        for key in [
+            DataKey::FarmerCertList(account.clone()),
+            DataKey::VerifierCertList(account.clone()),
+            DataKey::BeneficiaryReceiptList(account.clone()),
+            DataKey::Verifier(account),
+        ] {
+            if env.storage().persistent().has(&key) {
+                Self::extend_persistent_ttl(&env, &key);
+            }
+        }
+        Self::extend_instance_ttl(&env);
+    }
+
+    /// Propone un certificado que quedará pendiente hasta alcanzar el quórum
+    /// 
+    /// El verificador que propone cuenta como la primera aprobación. Si la
+    /// configuración vigente exige una sola aprobación, el certificado se
+    /// acuña inmediatamente.
+    /// 
+    /// # Argumentos
+    /// * `env` - El entorno del contrato
+    /// * `certificate_id` - ID único del certificado (u32)
+    /// * `record` - Los datos completos del certificado de verificación
+    /// 
+    /// # Retorna
+    /// `bool` - `true` si el certificado quedó acuñado
+    /// 
+    /// # Errores
+    /// * `PausableError::EnforcedPause` si el contrato está pausado
+    /// * `ContractError::VerifierNotAuthorized` si el verificador no está registrado o está suspendido
+    /// * `ContractError::InvalidInput` si los datos son inválidos
+    /// * `ContractError::AlreadyExists` si el certificado o una propuesta vigente ya existe
+    /// * `ContractError::TokenMintFailed` si se alcanzó el quórum y no se pudieron acuñar los CXO
+    /// 
+    /// # Emite
+    /// * `CertificateProposedEvent`
+    pub fn propose_certificate(
+        env: Env,
+        certificate_id: u32,
+        record: VerificationRecord,
+    ) -> Result<bool, ContractError> {
+        // ✅ PAUSA: Bloqueado mientras el contrato está pausado
+        pausable::when_not_paused(&env);
+
+        // ✅ AUTORIZACIÓN: El verificador que propone debe firmar
+        record.verifier_address.require_auth();
+        Self::require_active_verifier(&env, &record.verifier_address)?;
+        Self::validate_new_certificate(&env, certificate_id, &record)?;
+
+        let proposal_key = DataKey::Proposal(certificate_id);
+        let config = Self::get_quorum_config(env.clone());
+        let expires_at_ledger = env.ledger().sequence().saturating_add(config.proposal_ttl_ledgers);
+        let proposal = CertificateProposal {
+            record: record.clone(),
+            approvals: Vec::from_array(&env, [record.verifier_address.clone()]),
+            required_approvals: config.required_approvals,
+            expires_at_ledger,
+        };
+
+        CertificateProposedEvent {
+            certificate_id,
+            verifier: record.verifier_address.clone(),
+            co2e_kg: record.co2e_kg,
+            expires_at_ledger,
+        }
+        .publish(&env);
+
+        if config.required_approvals <= 1 {
+            env.storage().persistent().remove(&proposal_key);
+            Self::issue_certificate(&env, certificate_id, record)?;
+            return Ok(true);
+        }
+
+        Self::write_persistent(&env, &proposal_key, &proposal);
+        Ok(false)
+    }
+
+    /// Aprueba (co-firma) una propuesta de certificado pendiente
+    /// 
+    /// Al alcanzar el quórum se aplican propietario, índices, contadores y la
+    /// acuñación de CXO, y la propuesta se elimina. Solo cuentan para el quórum
+    /// las aprobaciones de verificadores que siguen activos; las de verificadores
+    /// suspendidos o eliminados después de aprobar se ignoran.
+    /// 
+    /// # Argumentos
+    /// * `env` - El entorno del contrato
+    /// * `verifier` - Verificador activo que aprueba
+    /// * `certificate_id` - ID del certificado propuesto
+    /// 
+    /// # Retorna
+    /// `bool` - `true` si con esta aprobación el certificado quedó acuñado
+    /// 
+    /// # Errores
+    /// * `PausableError::EnforcedPause` si el contrato está pausado
+    /// * `ContractError::NotFound` si no existe la propuesta
+    /// * `ContractError::VerifierNotAuthorized` si el verificador no está registrado o está suspendido
+    /// * `ContractError::ProposalExpired` si la propuesta venció
+    /// * `ContractError::AlreadyApproved` si el verificador ya aprobó
+    /// * Cualquier error de validación de `mint_certificate` si al alcanzar el
+    ///   quórum el record ya no es válido (p. ej. `AlreadyExists`)
+    /// * `ContractError::TokenMintFailed` si no se pudieron acuñar los CXO
+    /// 
+    /// # Emite
+    /// * `ProposalApprovedEvent`
+    pub fn approve_proposal(env: Env, verifier: Address, certificate_id: u32) -> Result<bool, ContractError> {
+        // ✅ PAUSA: Bloqueado mientras el contrato está pausado
+        pausable::when_not_paused(&env);
+
+        // ✅ AUTORIZACIÓN: El verificador debe firmar y estar activo
+        verifier.require_auth();
+        Self::require_active_verifier(&env, &verifier)?;
+
+        let proposal_key = DataKey::Proposal(certificate_id);
+        let mut proposal: CertificateProposal = Self::read_persistent(&env, &proposal_key)
+            .ok_or(ContractError::NotFound)?;
+
+        if env.ledger().sequence() > proposal.expires_at_ledger {
+            return Err(ContractError::ProposalExpired);
+        }
+        if proposal.approvals.contains(&verifier) {
+            return Err(ContractError::AlreadyApproved);
+        }
+
+        proposal.approvals.push_back(verifier.clone());
+
+        // ✅ QUÓRUM: Solo cuentan los aprobadores que siguen activos
+        let mut approvals: u32 = 0;
+        for approver in proposal.approvals.iter() {
+            if Self::require_active_verifier(&env, &approver).is_ok() {
+                approvals += 1;
+            }
+        }
+
+        ProposalApprovedEvent {
+            certificate_id,
+            verifier,
+            approvals,
+        }
+        .publish(&env);
+
+        // ✅ QUÓRUM ALCANZADO: revalidar (ID, metodología y estimación) y acuñar el certificado
+        if approvals >= proposal.required_approvals {
+            env.storage().persistent().remove(&proposal_key);
+            Self::validate_new_certificate(&env, certificate_id, &proposal.record)?;
+            Self::issue_certificate(&env, certificate_id, proposal.record)?;
+            return Ok(true);
+        }
+
+        Self::write_persistent(&env, &proposal_key, &proposal);
+        Ok(false)
+    }
+
+    /// Elimina una propuesta vencida
+    /// 
+    /// Puede invocarlo cualquiera una vez pasado `expires_at_ledger`.
+    /// 
+    /// # Errores
+    /// * `ContractError::NotFound` si no existe la propuesta
+    /// * `ContractError::InvalidInput` si la propuesta aún está vigente
+    pub fn remove_expired_proposal(env: Env, certificate_id: u32) -> Result<(), ContractError> {
+        let proposal_key = DataKey::Proposal(certificate_id);
+        let proposal: CertificateProposal = Self::read_persistent(&env, &proposal_key)
+            .ok_or(ContractError::NotFound)?;
+
+        if env.ledger().sequence() <= proposal.expires_at_ledger {
+            return Err(ContractError::InvalidInput);
+        }
+
+        env.storage().persistent().remove(&proposal_key);
+        Ok(())
+    }
+
+    /// Obtiene una propuesta de certificado pendiente
+    /// 
+    /// # Errores
+    /// * `ContractError::NotFound` si no existe la propuesta
+    pub fn get_proposal(env: Env, certificate_id: u32) -> Result<CertificateProposal, ContractError> {
+        Self::read_persistent(&env, &DataKey::Proposal(certificate_id))
+            .ok_or(ContractError::NotFound)
+    }
+
+    /// Configura la ventana de impugnación (en ledgers) de los certificados nuevos
+    /// 
+    /// Solo puede ser invocado por una dirección con el rol `config_manager`. Con
+    /// `ledgers = 0` (valor por defecto) los certificados se activan y acuñan CXO
+    /// de inmediato.
+    /// 
+    /// # Errores
+    /// * `AccessControlError::Unauthorized` si el llamador no tiene el rol `config_manager`
+    /// 
+    /// # Emite
+    /// * `ChallengeWindowUpdatedEvent` con la nueva duración
+    pub fn set_challenge_window(env: Env, caller: Address, ledgers: u32) -> Result<(), ContractError> {
+        Self::ensure_role(&env, &caller, CONFIG_MANAGER_ROLE);
+
+        env.storage().instance().set(&DataKey::ChallengeWindow, &ledgers);
+
+        // ✅ EMITIR EVENTO: Notificar la nueva ventana de impugnación
+        ChallengeWindowUpdatedEvent { ledgers }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Obtiene la duración de la ventana de impugnación en ledgers
+    pub fn get_challenge_window(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::ChallengeWindow).unwrap_or(0)
+    }
+
+    /// Obtiene el último ledger de la ventana de impugnación de un certificado pendiente
+    /// 
+    /// # Errores
+    /// * `ContractError::NotFound` si el certificado no está pendiente
+    pub fn get_challenge_deadline(env: Env, certificate_id: u32) -> Result<u32, ContractError> {
+        Self::read_persistent(&env, &DataKey::ChallengeDeadline(certificate_id))
+            .ok_or(ContractError::NotFound)
+    }
+
+    /// Impugna un certificado pendiente durante su ventana de disputa
+    /// 
+    /// Puede invocarlo cualquier verificador registrado y activo o el rol
+    /// `revoker`. El certificado queda bloqueado hasta que `revoker` resuelva.
+    /// 
+    /// # Argumentos
+    /// * `env` - El entorno del contrato
+    /// * `challenger` - Verificador activo o dirección con el rol `revoker`
+    /// * `certificate_id` - ID del certificado pendiente
+    /// * `evidence_hash` - Hash de la evidencia presentada
+    /// 
+    /// # Errores
+    /// * `ContractError::NotAuthorized` si `challenger` no es verificador activo ni tiene el rol `revoker`
+    /// * `ContractError::NotFound` si el certificado no existe
+    /// * `ContractError::InvalidStatusTransition` si el certificado no está pendiente
+    /// * `ContractError::ChallengeWindowClosed` si la ventana ya cerró
+    /// * `ContractError::CertificateChallenged` si ya existe una impugnación
+    /// 
+    /// # Emite
+    /// * `CertificateChallengedEvent`
+    pub fn challenge_certificate(
+        env: Env,
+        challenger: Address,
+        certificate_id: u32,
+        evidence_hash: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        // ✅ AUTORIZACIÓN: Verificador activo o rol `revoker`
+        challenger.require_auth();
+        if access_control::has_role(&env, &challenger, &Symbol::new(&env, REVOKER_ROLE)).is_none()
+            && Self::require_active_verifier(&env, &challenger).is_err()
+        {
+            return Err(ContractError::NotAuthorized);
+        }
+
+        let deadline = Self::require_pending(&env, certificate_id)?;
+        if env.ledger().sequence() > deadline {
+            return Err(ContractError::ChallengeWindowClosed);
+        }
+
+        Self::write_persistent(&env, &DataKey::Challenge(certificate_id), &Challenge {
+            challenger: challenger.clone(),
+            evidence_hash: evidence_hash.clone(),
+            raised_at_ledger: env.ledger().sequence(),
+        });
+
+        CertificateChallengedEvent {
+            certificate_id,
+            challenger,
+            evidence_hash,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Obtiene la impugnación abierta sobre un certificado
+    /// 
+    /// # Errores
+    /// * `ContractError::NotFound` si el certificado no tiene impugnación abierta
+    pub fn get_challenge(env: Env, certificate_id: u32) -> Result<Challenge, ContractError> {
+        Self::read_persistent(&env, &DataKey::Challenge(certificate_id))
+            .ok_or(ContractError::NotFound)
+    }
+
+    /// Activa un certificado pendiente y acuña sus CXO al agricultor
+    /// 
+    /// Puede invocarlo cualquiera una vez cerrada la ventana de impugnación sin
+    /// impugnaciones.
+    /// 
+    /// # Errores
+    /// * `PausableError::EnforcedPause` si el contrato está pausado
+    /// * `ContractError::NotFound` si el certificado no existe
+    /// * `ContractError::InvalidStatusTransition` si el certificado no está pendiente
+    /// * `ContractError::CertificateChallenged` si tiene una impugnación abierta
+    /// * `ContractError::ChallengeWindowOpen` si la ventana sigue abierta
+    /// * `ContractError::TokenMintFailed` si no se pudieron acuñar los CXO
+    /// 
+    /// # Emite
+    /// * `CertificateFinalizedEvent`
+    pub fn finalize_certificate(env: Env, certificate_id: u32) -> Result<(), ContractError> {
+        // ✅ PAUSA: Bloqueado mientras el contrato está pausado
+        pausable::when_not_paused(&env);
+
+        let deadline = Self::require_pending(&env, certificate_id)?;
+        if env.ledger().sequence() <= deadline {
+            return Err(ContractError::ChallengeWindowOpen);
+        }
+
+        Self::activate_pending(&env, certificate_id)
+    }
+
+    /// Resuelve la impugnación de un certificado pendiente
+    /// 
+    /// Solo puede ser invocado por el rol `revoker`. Si la impugnación procede
+    /// el certificado se revoca con la evidencia como motivo; si se desestima,
+    /// el certificado se activa y se acuñan sus CXO.
+    /// 
+    /// # Argumentos
+    /// * `env` - El entorno del contrato
+    /// * `caller` - Dirección con el rol `revoker`
+    /// * `certificate_id` - ID del certificado impugnado
+    /// * `uphold` - `true` para dar la impugnación por válida
+    /// 
+    /// # Errores
+    /// * `AccessControlError::Unauthorized` si el llamador no tiene el rol `revoker`
+    /// * `ContractError::NotFound` si el certificado no tiene impugnación abierta
+    /// * `ContractError::TokenMintFailed` si al desestimar no se pudieron acuñar los CXO
+    /// 
+    /// # Emite
+    /// * `ChallengeResolvedEvent` y, según el caso, `CertificateRevokedEvent` o `CertificateFinalizedEvent`
+    pub fn resolve_challenge(
+        env: Env,
+        caller: Address,
+        certificate_id: u32,
+        uphold: bool,
+    ) -> Result<(), ContractError> {
+        Self::ensure_role(&env, &caller, REVOKER_ROLE);
+
+        let challenge_key = DataKey::Challenge(certificate_id);
+        let challenge: Challenge = Self::read_persistent(&env, &challenge_key)
+            .ok_or(ContractError::NotFound)?;
+        env.storage().persistent().remove(&challenge_key);
+
+        ChallengeResolvedEvent { certificate_id, upheld: uphold }.publish(&env);
+
+        if uphold {
+            // ✅ Pending -> Revoked: no hay CO2e ni CXO que descontar
+            Self::transition_status(&env, certificate_id, CertificateStatus::Revoked)?;
+            env.storage().persistent().remove(&DataKey::ChallengeDeadline(certificate_id));
+            Self::record_revocation(&env, certificate_id, caller, challenge.evidence_hash, 0, (0, 0));
+            Ok(())
+        } else {
+            Self::activate_pending(&env, certificate_id)
+        }
+    }
+
+    /// Verifica que un certificado esté pendiente y sin impugnar; retorna el fin de su ventana
+    fn require_pending(env: &Env, certificate_id: u32) -> Result<u32, ContractError> {
+        match Self::read_status(env, certificate_id) {
+            Some(CertificateStatus::Pending) => {}
+            Some(_) => return Err(ContractError::InvalidStatusTransition),
+            None => return Err(ContractError::NotFound),
+        }
+        if env.storage().persistent().has(&DataKey::Challenge(certificate_id)) {
+            return Err(ContractError::CertificateChallenged);
+        }
+        Self::read_persistent(env, &DataKey::ChallengeDeadline(certificate_id))
+            .ok_or(ContractError::NotFound)
+    }
+
+    /// Pending -> Active: acredita el CO2e y acuña los CXO del agricultor
+    fn activate_pending(env: &Env, certificate_id: u32) -> Result<(), ContractError> {
+        let record: VerificationRecord = Self::read_certificate(env, certificate_id)
+            .ok_or(ContractError::NotFound)?;
+
+        Self::transition_status(env, certificate_id, CertificateStatus::Active)?;
+        env.storage().persistent().remove(&DataKey::ChallengeDeadline(certificate_id));
+        Self::add_co2e_to_total(env, record.co2e_kg);
+
+        // ✅ INVOCACIÓN CROSS-CONTRACT: Acuñar los CXO liberados
+        Self::mint_cxo(env, &record.farmer_address, record.co2e_kg)?;
+
+        CertificateFinalizedEvent {
+            certificate_id,
+            farmer: record.farmer_address,
+            co2e_kg: record.co2e_kg,
+        }
+        .publish(env);
+
+        Ok(())
+    }
+
+    /// Configura el factor de emisión CONADESUCA de una región y zafra bajo una
+    /// versión de metodología
+    /// 
+    /// Solo puede ser invocado por el rol `registry_manager`. El factor acota el CO2e que
+    /// puede declararse por hectárea no quemada en los certificados emitidos bajo
+    /// `methodology_version`, que luego aplica su buffer y descuento por
+    /// incertidumbre. La versión 0 corresponde a los certificados emitidos sin
+    /// metodología registrada.
+    /// 
+    /// # Argumentos
+    /// * `env` - El entorno del contrato
+    /// * `caller` - Dirección con el rol `registry_manager`
+    /// * `methodology_version` - Versión de metodología registrada, o 0
+    /// * `region` - Región (p. ej. `morelos`)
+    /// * `season` - Zafra (año de inicio)
+    /// * `kg_co2e_per_hectare` - Kilogramos de CO2e evitados por hectárea no quemada
+    /// 
+    /// # Errores
+    /// * `AccessControlError::Unauthorized` si el llamador no tiene el rol `registry_manager`
+    /// * `ContractError::InvalidInput` si el factor es 0
+    /// * `ContractError::MethodologyNotFound` si la versión no está registrada
+    /// 
+    /// # Emite
+    /// * `EmissionFactorSetEvent`
+    pub fn set_emission_factor(
+        env: Env,
+        caller: Address,
+        methodology_version: u32,
+        region: Symbol,
+        season: u32,
+        kg_co2e_per_hectare: u128,
+    ) -> Result<(), ContractError> {
+        Self::ensure_role(&env, &caller, REGISTRY_MANAGER_ROLE);
+
+        if kg_co2e_per_hectare == 0 {
+            return Err(ContractError::InvalidInput);
+        }
+        if methodology_version != 0 {
+            Self::get_methodology(env.clone(), methodology_version)?;
+        }
+
+        Self::write_persistent(
+            &env,
+            &DataKey::EmissionFactor(methodology_version, region.clone(), season),
+            &kg_co2e_per_hectare,
+        );
+
+        EmissionFactorSetEvent {
+            methodology_version,
+            region,
+            season,
+            kg_co2e_per_hectare,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Obtiene el factor de emisión (kg CO2e por hectárea) de una región y zafra
+    /// configurado para una versión de metodología
+    /// 
+    /// # Errores
+    /// * `ContractError::EmissionFactorNotSet` si no hay factor configurado
+    pub fn get_emission_factor(
+        env: Env,
+        methodology_version: u32,
+        region: Symbol,
+        season: u32,
+    ) -> Result<u128, ContractError> {
+        Self::read_persistent(&env, &DataKey::EmissionFactor(methodology_version, region, season))
+            .ok_or(ContractError::EmissionFactorNotSet)
+    }
+
+    /// Registra una nueva versión de la metodología y la marca como vigente
+    /// 
+    /// Solo puede ser invocado por el rol `registry_manager`. Las versiones se numeran
+    /// de forma incremental desde 1 y nunca se modifican.
+    /// 
+    /// # Argumentos
+    /// * `env` - El entorno del contrato
+    /// * `caller` - Dirección con el rol `registry_manager`
+    /// * `params` - Factor de emisión por defecto, buffer y descuento por incertidumbre
+    /// 
+    /// # Errores
+    /// * `AccessControlError::Unauthorized` si el llamador no tiene el rol `registry_manager`
+    /// * `ContractError::InvalidInput` si el factor es 0 o las deducciones suman 100% o más
+    /// 
+    /// # Emite
+    /// * `MethodologyRegisteredEvent`
+    /// 
+    /// # Retorna
+    /// La versión asignada
+    pub fn register_methodology(
+        env: Env,
+        caller: Address,
+        params: MethodologyParams,
+    ) -> Result<u32, ContractError> {
+        Self::ensure_role(&env, &caller, REGISTRY_MANAGER_ROLE);
+
+        if params.kg_co2e_per_hectare == 0 {
+            return Err(ContractError::InvalidInput);
+        }
+        let deductions_bps = params.buffer_bps
+            .checked_add(params.uncertainty_discount_bps)
+            .ok_or(ContractError::InvalidInput)?;
+        if deductions_bps >= BPS_DENOMINATOR {
+            return Err(ContractError::InvalidInput);
+        }
+
+        // ✅ INMUTABILIDAD: Siempre una versión nueva; las anteriores no se tocan
+        let version = Self::get_current_methodology_version(env.clone()) + 1;
+        Self::write_persistent(&env, &DataKey::Methodology(version), &params);
+        env.storage().instance().set(&DataKey::CurrentMethodology, &version);
+
+        MethodologyRegisteredEvent {
+            version,
+            kg_co2e_per_hectare: params.kg_co2e_per_hectare,
+            buffer_bps: params.buffer_bps,
+            uncertainty_discount_bps: params.uncertainty_discount_bps,
+        }
+        .publish(&env);
+
+        Ok(version)
+    }
+
+    /// Obtiene los parámetros de una versión de metodología
+    /// 
+    /// # Errores
+    /// * `ContractError::MethodologyNotFound` si la versión no está registrada
+    pub fn get_methodology(env: Env, version: u32) -> Result<MethodologyParams, ContractError> {
+        Self::read_persistent(&env, &DataKey::Methodology(version))
+            .ok_or(ContractError::MethodologyNotFound)
+    }
+
+    /// Obtiene la versión de metodología vigente (0 si no hay ninguna registrada)
+    pub fn get_current_methodology_version(env: Env) -> u32 {
+        env.storage().instance()
+            .get(&DataKey::CurrentMethodology)
+            .unwrap_or(0)
+    }
+
+    /// Estima el CO2e evitado (en kg) por una superficie no quemada
+    /// 
+    /// Consulta de solo lectura para el frontend con la metodología vigente:
+    /// `hectares * factor(region, season)` menos buffer y descuento por
+    /// incertidumbre, con el factor por defecto de la versión si la región/zafra
+    /// no tiene uno propio. Es la cota máxima que acepta `mint_certificate`.
+    /// 
+    /// # Errores
+    /// * `ContractError::EmissionFactorNotSet` si no hay metodología registrada
+    ///   ni factor configurado para la región/zafra
+    /// * `ContractError::InvalidInput` si el cálculo desborda
+    pub fn estimate_co2e(env: Env, hectares: u32, region: Symbol, season: u32) -> Result<u128, ContractError> {
+        let version = Self::get_current_methodology_version(env.clone());
+        Self::estimate_co2e_for_version(&env, hectares, region, season, version)
+    }
+
+    /// Estima el CO2e con los parámetros de una versión de metodología concreta
+    /// 
+    /// Los factores por región/zafra se configuran por versión, de modo que
+    /// cambiarlos en una versión no altera las demás. El factor de la
+    /// región/zafra tiene prioridad sobre el por defecto de la versión. La
+    /// versión 0 no tiene factor por defecto ni aplica deducciones.
+    fn estimate_co2e_for_version(
+        env: &Env,
+        hectares: u32,
+        region: Symbol,
+        season: u32,
+        version: u32,
+    ) -> Result<u128, ContractError> {
+        let regional_factor: Option<u128> =
+            Self::read_persistent(env, &DataKey::EmissionFactor(version, region, season));
+
+        let (factor, deductions_bps) = if version == 0 {
+            (regional_factor.ok_or(ContractError::EmissionFactorNotSet)?, 0)
+        } else {
+            let params = Self::get_methodology(env.clone(), version)?;
+            (
+                regional_factor.unwrap_or(params.kg_co2e_per_hectare),
+                params.buffer_bps + params.uncertainty_discount_bps,
+            )
+        };
+
+        let gross = factor.checked_mul(hectares as u128).ok_or(ContractError::InvalidInput)?;
+        let deducted = gross
+            .checked_mul(deductions_bps as u128)
+            .ok_or(ContractError::InvalidInput)?
+            / BPS_DENOMINATOR as u128;
+        Ok(gross - deducted)
+    }
+
+    /// Registra una parcela de caña de azúcar
+    /// 
+    /// Solo puede ser invocado por el rol `registry_manager`. Los certificados deben
+    /// referenciar una parcela registrada del mismo agricultor.
+    /// 
+    /// # Argumentos
+    /// * `env` - El entorno del contrato
+    /// * `caller` - Dirección con el rol `registry_manager`
+    /// * `parcel_id` - ID único de la parcela
+    /// * `farmer` - Agricultor titular
+    /// * `geo_hash` - Hash del polígono georreferenciado
+    /// * `area_hectares` - Superficie total en hectáreas
+    /// 
+    /// # Errores
+    /// * `AccessControlError::Unauthorized` si el llamador no tiene el rol `registry_manager`
+    /// * `ContractError::AlreadyExists` si la parcela ya está registrada
+    /// * `ContractError::InvalidInput` si la superficie es 0
+    /// 
+    /// # Emite
+    /// * `ParcelRegisteredEvent`
+    pub fn register_parcel(
+        env: Env,
+        caller: Address,
+        parcel_id: u32,
+        farmer: Address,
+        geo_hash: BytesN<32>,
+        area_hectares: u32,
+    ) -> Result<(), ContractError> {
+        Self::ensure_role(&env, &caller, REGISTRY_MANAGER_ROLE);
+
+        if area_hectares == 0 {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let key = DataKey::Parcel(parcel_id);
+        if env.storage().persistent().has(&key) {
+            return Err(ContractError::AlreadyExists);
+        }
+
+        let parcel = ParcelInfo {
+            farmer: farmer.clone(),
+            geo_hash,
+            area_hectares,
+            registered_at: env.ledger().timestamp(),
+        };
+        Self::write_persistent(&env, &key, &parcel);
+
+        ParcelRegisteredEvent {
+            parcel_id,
+            farmer,
+            area_hectares,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Obtiene una parcela registrada
+    /// 
+    /// # Errores
+    /// * `ContractError::ParcelNotFound` si la parcela no está registrada
+    pub fn get_parcel(env: Env, parcel_id: u32) -> Result<ParcelInfo, ContractError> {
+        Self::read_persistent(&env, &DataKey::Parcel(parcel_id))
+            .ok_or(ContractError::ParcelNotFound)
+    }
+
+    /// Obtiene el certificado emitido para una parcela en una zafra, si existe
+    pub fn get_parcel_certificate(env: Env, parcel_id: u32, season: u32) -> Option<u32> {
+        Self::read_persistent(&env, &DataKey::ParcelSeason(parcel_id, season))
+    }
+
+    /// Migra un certificado almacenado con el formato anterior de `VerificationRecord`
+    /// 
+    /// Solo puede ser invocado por el rol `upgrader`. `record` debe conservar el
+    /// verificador, agricultor, hectáreas y hash almacenados, con `co2e_kg` igual
+    /// a las `co2e_tons` originales × 1,000, y aporta región, zafra, metodología,
+    /// parcela, vintage, periodo de monitoreo, proyecto y municipio; el
+    /// certificado se añade a los índices por vintage y proyecto y reserva su
+    /// (parcela, zafra) como en una acuñación, de modo que no puede volver a certificarse.
+    /// Se ejecuta con el contrato pausado, para que no se acuñen ni muevan
+    /// certificados sobre records sin migrar. Las propuestas de quórum
+    /// anteriores a la migración deben volver a proponerse.
+    /// Los balances de CXO en unidades enteras se reescalan aparte con
+    /// `migrate_balances` de CarbonToken; hasta entonces retirar el certificado
+    /// falla con `TokenBurnFailed`.
+    /// 
+    /// # Argumentos
+    /// * `env` - El entorno del contrato
+    /// * `caller` - Dirección con el rol `upgrader`
+    /// * `certificate_id` - ID del certificado a migrar
+    /// * `record` - Record completo en el formato nuevo
+    /// 
+    /// # Errores
+    /// * `AccessControlError::Unauthorized` si el llamador no tiene el rol `upgrader`
+    /// * `PausableError::ExpectedPause` si el contrato no está pausado
+    /// * `ContractError::NotFound` si el certificado no existe
+    /// * `ContractError::AlreadyExists` si el certificado ya está en el formato nuevo
+    /// * `ContractError::InvalidInput` si `record` no coincide con el almacenado o el periodo es inválido
+    /// * `ContractError::ParcelNotFound` si la parcela no está registrada
+    /// * `ContractError::ParcelFarmerMismatch` si la parcela es de otro agricultor
+    /// * `ContractError::HectaresExceedParcelArea` si las hectáreas exceden la superficie de la parcela
+    /// * `ContractError::ParcelSeasonAlreadyCertified` si la parcela ya tiene certificado en la zafra
+    /// 
+    /// # Emite
+    /// * `CertificateMigratedEvent`
+    pub fn migrate_certificate_record(
+        env: Env,
+        caller: Address,
+        certificate_id: u32,
+        record: VerificationRecord,
+    ) -> Result<(), ContractError> {
+        Self::ensure_role(&env, &caller, UPGRADER_ROLE);
+        pausable::when_paused(&env);
+        Self::migrate_record(&env, certificate_id, record)
+    }
+
+    /// Migra en lote certificados almacenados con el formato anterior de `VerificationRecord`
+    /// 
+    /// Cada elemento sigue las reglas de `migrate_certificate_record`. El lote es
+    /// todo o nada y admite hasta 50 certificados para acotar el costo de cada
+    /// transacción; una migración grande se reparte en varias llamadas.
+    /// 
+    /// # Argumentos
+    /// * `env` - El entorno del contrato
+    /// * `caller` - Dirección con el rol `upgrader`
+    /// * `records` - Pares (ID del certificado, record completo en el formato nuevo)
+    /// 
+    /// # Errores
+    /// * `AccessControlError::Unauthorized` si el llamador no tiene el rol `upgrader`
+    /// * `PausableError::ExpectedPause` si el contrato no está pausado
+    /// * `ContractError::InvalidInput` si el lote está vacío o excede 50 certificados
+    /// * Cualquier error de `migrate_certificate_record` para alguno de los elementos
+    /// 
+    /// # Emite
+    /// * `CertificateMigratedEvent` por cada certificado
+    pub fn migrate(env: Env, caller: Address, records: Vec<(u32, VerificationRecord)>) -> Result<(), ContractError> {
+        Self::ensure_role(&env, &caller, UPGRADER_ROLE);
+
+        // ✅ PAUSA: Sin movimientos sobre records sin migrar
+        pausable::when_paused(&env);
+
+        // ✅ VALIDACIÓN: Lote no vacío y acotado
+        if records.is_empty() || records.len() > MAX_MIGRATION_BATCH {
+            return Err(ContractError::InvalidInput);
+        }
+
+        for (certificate_id, record) in records.iter() {
+            Self::migrate_record(&env, certificate_id, record)?;
+        }
+
+        Ok(())
+    }
+
+    /// Reescribe un record del formato anterior al vigente sin alterar los datos existentes
+    fn migrate_record(env: &Env, certificate_id: u32, record: VerificationRecord) -> Result<(), ContractError> {
+        let key = DataKey::Certificates(certificate_id);
+        let raw: Map<Symbol, Val> = env.storage().persistent().get(&key)
+            .ok_or(ContractError::NotFound)?;
+        if raw.contains_key(symbol_short!("vintage")) {
+            return Err(ContractError::AlreadyExists);
+        }
+        let legacy: LegacyVerificationRecord = env.storage().persistent().get(&key)
+            .ok_or(ContractError::NotFound)?;
+
+        // ✅ INMUTABILIDAD: Solo se añaden los campos nuevos; las toneladas pasan a kg
+        let co2e_kg = legacy.co2e_tons.checked_mul(KG_PER_TON)
+            .ok_or(ContractError::InvalidInput)?;
+        if record.verifier_address != legacy.verifier_address
+            || record.farmer_address != legacy.farmer_address
+            || record.hectares_not_burned != legacy.hectares_not_burned
+            || record.co2e_kg != co2e_kg
+            || record.metadata_hash != legacy.metadata_hash
+        {
+            return Err(ContractError::InvalidInput);
+        }
+        Self::validate_record_provenance(&record)?;
+
+        // ✅ DOBLE CONTEO: Parcela del agricultor, superficie acotada y libre en la zafra
+        Self::claim_parcel_season(env, certificate_id, &record)?;
+
+        env.storage().persistent().set(&key, &record);
+        Self::extend_persistent_ttl(env, &key);
+        Self::add_to_list(env, &DataKey::VintageCertList(record.vintage), certificate_id);
+        Self::add_to_list(env, &DataKey::ProjectCertList(record.project_id.clone()), certificate_id);
+
+        CertificateMigratedEvent {
+            certificate_id,
+            vintage: record.vintage,
+            project_id: record.project_id,
+        }
+        .publish(env);
+
+        Ok(())
+    }
+
+    /// Verifica vintage y periodo de monitoreo de un record
+    fn validate_record_provenance(record: &VerificationRecord) -> Result<(), ContractError> {
+        if record.vintage == 0 || record.monitoring_start >= record.monitoring_end {
+            return Err(ContractError::InvalidInput);
+        }
+        Ok(())
+    }
+
+    /// Valida los datos de un certificado nuevo y que su ID esté libre (sin
+    /// certificado ni propuesta vigente)
+    fn validate_new_certificate(
+        env: &Env,
+        certificate_id: u32,
+        record: &VerificationRecord,
+    ) -> Result<(), ContractError> {
+        if record.hectares_not_burned == 0 {
+            return Err(ContractError::InvalidInput);
+        }
+        if record.co2e_kg == 0 {
+            return Err(ContractError::InvalidInput);
+        }
+        Self::validate_record_provenance(record)?;
+
+        // ✅ METODOLOGÍA: El record se emite bajo la versión vigente
+        if record.methodology_version != Self::get_current_methodology_version(env.clone()) {
+            return Err(ContractError::MethodologyVersionMismatch);
+        }
+
+        // ✅ METODOLOGÍA: El CO2e declarado no puede exceder la estimación por hectárea
+        let estimate = Self::estimate_co2e_for_version(
+            env,
+            record.hectares_not_burned,
+            record.region.clone(),
+            record.season,
+            record.methodology_version,
+        )?;
+        if record.co2e_kg > estimate {
+            return Err(ContractError::Co2eExceedsEstimate);
+        }
+
+        // Verificar que el certificado no existe ya (ni fue retirado: sus recibos lo referencian)
+        let key = DataKey::Certificates(certificate_id);
+        if env.storage().persistent().get::<DataKey, VerificationRecord>(&key).is_some()
+            || env.storage().persistent().has(&DataKey::Status(certificate_id))
+        {
+            return Err(ContractError::AlreadyExists);
+        }
+
+        // ✅ Una propuesta vigente bloquea el ID; una vencida puede reemplazarse
+        let proposal: Option<CertificateProposal> = Self::read_persistent(env, &DataKey::Proposal(certificate_id));
+        if proposal.is_some_and(|proposal| env.ledger().sequence() <= proposal.expires_at_ledger) {
+            return Err(ContractError::AlreadyExists);
+        }
+
+        // ✅ DOBLE CONTEO: Parcela del agricultor, superficie acotada y libre en la zafra
+        Self::validate_parcel(env, record)
+    }
+
+    /// Verifica la parcela del record y que no tenga certificado en la zafra
+    fn validate_parcel(env: &Env, record: &VerificationRecord) -> Result<(), ContractError> {
+        let parcel = Self::get_parcel(env.clone(), record.parcel_id)?;
+        if parcel.farmer != record.farmer_address {
+            return Err(ContractError::ParcelFarmerMismatch);
+        }
+        if record.hectares_not_burned > parcel.area_hectares {
+            return Err(ContractError::HectaresExceedParcelArea);
+        }
+        if env.storage().persistent().has(&DataKey::ParcelSeason(record.parcel_id, record.season)) {
+            return Err(ContractError::ParcelSeasonAlreadyCertified);
+        }
+        Ok(())
+    }
+
+    /// Reserva la (parcela, zafra) del record para `certificate_id`
+    fn claim_parcel_season(env: &Env, certificate_id: u32, record: &VerificationRecord) -> Result<(), ContractError> {
+        Self::validate_parcel(env, record)?;
+        Self::write_persistent(env, &DataKey::ParcelSeason(record.parcel_id, record.season), &certificate_id);
+        Ok(())
+    }
+
+    /// Reasigna a `to_id` la (parcela, zafra) del record si la reserva uno de `from_ids`
+    fn repoint_parcel_season(env: &Env, record: &VerificationRecord, from_ids: &Vec<u32>, to_id: u32) {
+        let key = DataKey::ParcelSeason(record.parcel_id, record.season);
+        let holder: Option<u32> = env.storage().persistent().get(&key);
+        if holder.is_some_and(|holder| from_ids.contains(holder)) {
+            Self::write_persistent(env, &key, &to_id);
+        }
+    }
+
+    /// Acumula kilogramos retirados parcialmente en el historial de un certificado
+    fn add_certificate_retired(env: &Env, certificate_id: u32, co2e_kg: u128) {
+        if co2e_kg == 0 {
+            return;
+        }
+        let retired_key = DataKey::CertificateRetired(certificate_id);
+        let retired: u128 = env.storage().persistent().get(&retired_key).unwrap_or(0);
+        Self::write_persistent(env, &retired_key, &(retired + co2e_kg));
+    }
+
+    /// Acuña un certificado ya validado: almacenamiento, contadores, CXO y evento
+    /// 
+    /// Con ventana de impugnación configurada el certificado queda `Pending` y
+    /// sus CXO se acuñan en `finalize_certificate`.
+    fn issue_certificate(
+        env: &Env,
+        certificate_id: u32,
+        record: VerificationRecord,
+    ) -> Result<(), ContractError> {
+        // ✅ DOBLE CONTEO: Reservar (parcela, zafra); revalida propuestas que compitan por ella
+        Self::claim_parcel_season(env, certificate_id, &record)?;
+
+        let challenge_window = Self::get_challenge_window(env.clone());
+
+        if challenge_window > 0 {
+            // ✅ PENDIENTE: el NFT existe, pero el CO2e y los CXO esperan a que cierre la ventana
+            Self::insert_certificate(env, certificate_id, &record, &record.farmer_address, CertificateStatus::Pending);
+            let deadline = env.ledger().sequence().saturating_add(challenge_window);
+            Self::write_persistent(env, &DataKey::ChallengeDeadline(certificate_id), &deadline);
+        } else {
+            // Almacenar el certificado, sus índices y su propietario inicial
+            // ✅ El agricultor es el propietario inicial del NFT
+            Self::insert_certificate(env, certificate_id, &record, &record.farmer_address, CertificateStatus::Active);
+
+            // Actualizar contador global de CO2e
+            Self::add_co2e_to_total(env, record.co2e_kg);
+
+            // ✅ INVOCACIÓN CROSS-CONTRACT: Acuñar tokens CXO
+            // Si la acuñación falla se retorna error y el host revierte todas las
+            // escrituras anteriores (certificado, índices, contadores y propietario)
+            Self::mint_cxo(env, &record.farmer_address, record.co2e_kg)?;
+        }
+
+        // Obtener timestamp de la acuñación
+        let timestamp = env.ledger().timestamp();
+
+        // ✅ EMITIR EVENTO: Notificar el acuñamiento del certificado
+        CertificateMintedEvent {
+            certificate_id,
+            farmer: record.farmer_address,
+            verifier: record.verifier_address,
+            co2e_kg_minted: record.co2e_kg,
+            timestamp,
+        }
+        .publish(env);
+
+        Ok(())
+    }
+
+    /// Registra en `stellar-access` el traspaso de `admin` a `new_admin`
+    fn start_admin_transfer(
+        env: &Env,
+        admin: Address,
+        new_admin: Address,
+        expiration_ledger: u32,
+    ) -> Result<(), ContractError> {
+        if new_admin == admin || expiration_ledger < env.ledger().sequence() {
+            return Err(ContractError::InvalidInput);
+        }
+
+        access_control::transfer_admin_role(env, &new_admin, expiration_ledger);
+
+        AdminTransferProposedEvent {
+            current_admin: admin,
+            new_admin,
+            expiration_ledger,
+        }
+        .publish(env);
+
+        Ok(())
+    }
+
+    /// Cancela en `stellar-access` el traspaso pendiente
+    fn cancel_pending_admin(env: &Env, admin: Address) -> Result<(), ContractError> {
+        let new_admin = Self::get_pending_admin(env.clone()).ok_or(ContractError::NotFound)?;
+
+        access_control::transfer_admin_role(env, &new_admin, 0);
+
+        AdminTransferCancelledEvent {
+            admin,
+            new_admin,
+        }
+        .publish(env);
+
+        Ok(())
+    }
+
+    /// Completa en `stellar-access` el traspaso pendiente si no ha expirado
+    fn complete_admin_transfer(env: &Env) -> Result<(), ContractError> {
+        let new_admin = Self::get_pending_admin(env.clone()).ok_or(ContractError::NotFound)?;
+
+        let previous_admin = access_control::get_admin(env).ok_or(ContractError::NotFound)?;
+        // ✅ AUTORIZACIÓN: stellar-access exige la firma del administrador propuesto
+        access_control::accept_admin_transfer(env);
+
+        // ✅ ROLES: Los roles operativos del admin anterior pasan al nuevo
+        for role in OPERATIONAL_ROLES {
+            let role = Symbol::new(env, role);
+            if access_control::has_role(env, &previous_admin, &role).is_some() {
+                access_control::revoke_role_no_auth(env, &new_admin, &previous_admin, &role);
+                if access_control::has_role(env, &new_admin, &role).is_none() {
+                    access_control::grant_role_no_auth(env, &new_admin, &new_admin, &role);
+                }
+            }
+        }
+
+        AdminTransferredEvent {
+            previous_admin,
+            new_admin,
+        }
+        .publish(env);
+
+        Ok(())
+    }
+
+    /// Exige que `caller` tenga `role` y haya firmado la invocación
+    /// 
+    /// Rechaza con `AccessControlError::Unauthorized`, como `stellar-access`.
+    fn ensure_role(env: &Env, caller: &Address, role: &str) {
+        if access_control::has_role(env, caller, &Symbol::new(env, role)).is_none() {
+            panic_with_error!(env, AccessControlError::Unauthorized);
+        }
+        caller.require_auth();
+    }
+
+    /// Otorga al admin los roles operativos con los que arranca el contrato
+    /// 
+    /// `revoker` y `upgrader` quedan fuera: se otorgan de forma explícita.
+    fn grant_operational_roles(env: &Env, admin: &Address) {
+        for role in [REGISTRY_MANAGER_ROLE, CONFIG_MANAGER_ROLE, PAUSER_ROLE] {
+            access_control::grant_role_no_auth(env, admin, admin, &Symbol::new(env, role));
+        }
+    }
+
+    /// Verifica que la dirección sea un verificador registrado con estado `Active`
+    /// y con el rol `verifier`
+    fn require_active_verifier(env: &Env, verifier: &Address) -> Result<(), ContractError> {
+        if access_control::has_role(env, verifier, &Symbol::new(env, VERIFIER_ROLE)).is_none() {
+            return Err(ContractError::VerifierNotAuthorized);
+        }
+
+        let info: VerifierInfo = Self::read_persistent(env, &DataKey::Verifier(verifier.clone()))
+            .ok_or(ContractError::VerifierNotAuthorized)?;
+        if info.status != VerifierStatus::Active {
+            return Err(ContractError::VerifierNotAuthorized);
+        }
+        Ok(())
+    }
+
+    /// Actualiza el estado de un verificador registrado y emite el evento correspondiente
+    fn set_verifier_status(env: &Env, verifier: Address, status: VerifierStatus) -> Result<(), ContractError> {
+        let key = DataKey::Verifier(verifier.clone());
+        let mut info: VerifierInfo = env.storage().persistent().get(&key)
+            .ok_or(ContractError::NotFound)?;
+        info.status = status;
+        Self::write_persistent(env, &key, &info);
+
+        VerifierStatusChangedEvent { verifier, status }.publish(env);
+
+        Ok(())
+    }
+
+    /// Acuña los CXO equivalentes a `co2e_kg` para `to` en el contrato CarbonToken configurado
+    /// 
+    /// El certificador actúa como minter, por lo que debe tener ese rol en CarbonToken.
+    /// Cualquier fallo (token no configurado, cantidad fuera de rango o error del
+    /// contrato de token) se traduce en `ContractError::TokenMintFailed`.
+    fn mint_cxo(env: &Env, to: &Address, co2e_kg: u128) -> Result<(), ContractError> {
+        let token_contract_id: Address = env.storage().instance().get(&DataKey::TokenContractId)
+            .ok_or(ContractError::TokenMintFailed)?;
+        let amount = Self::kg_to_cxo_units(co2e_kg).ok_or(ContractError::TokenMintFailed)?;
 
-        // ✅ VALIDACIÓN DE DATOS: Verificar que los datos de entrada sean válidos
-        if record.hectares_not_burned == 0 {
-            return Err(ContractError::InvalidInput);
-        }
-        if record.co2e_tons == 0 {
-            return Err(ContractError::InvalidInput);
-        }
+        CarbonTokenClient::new(env, &token_contract_id)
+            .try_mint(&env.current_contract_address(), to, &amount)
+            .map_err(|_| ContractError::TokenMintFailed)?
+            .map_err(|_| ContractError::TokenMintFailed)
+    }
 
-        // Verificar que el certificado no existe ya
-        let key = DataKey::Certificates(certificate_id);
-        if env.storage().persistent().get::<DataKey, VerificationRecord>(&key).is_some() {
-            return Err(ContractError::AlreadyExists);
+    /// Quema los CXO equivalentes a `co2e_kg` de `from` en el contrato CarbonToken configurado
+    /// 
+    /// Cualquier fallo (token no configurado, balance insuficiente o error del
+    /// contrato de token) se traduce en `ContractError::TokenBurnFailed`.
+    fn burn_cxo(env: &Env, from: &Address, co2e_kg: u128) -> Result<(), ContractError> {
+        let token_contract_id: Address = env.storage().instance().get(&DataKey::TokenContractId)
+            .ok_or(ContractError::TokenBurnFailed)?;
+        let amount = Self::kg_to_cxo_units(co2e_kg).ok_or(ContractError::TokenBurnFailed)?;
+
+        CarbonTokenClient::new(env, &token_contract_id)
+            .try_burn(from, &amount)
+            .map_err(|_| ContractError::TokenBurnFailed)?
+            .map_err(|_| ContractError::TokenBurnFailed)
+    }
+
+    /// Recupera de `from` los CXO equivalentes a `co2e_kg`, hasta donde alcance su balance
+    /// 
+    /// # Retorna
+    /// `(i128, i128)` - Unidades de CXO (recuperadas, no recuperadas)
+    fn clawback_cxo(env: &Env, from: &Address, co2e_kg: u128) -> Result<(i128, i128), ContractError> {
+        let token_contract_id: Address = env.storage().instance().get(&DataKey::TokenContractId)
+            .ok_or(ContractError::TokenBurnFailed)?;
+        let amount = Self::kg_to_cxo_units(co2e_kg).ok_or(ContractError::TokenBurnFailed)?;
+
+        let token = CarbonTokenClient::new(env, &token_contract_id);
+        let clawed_back = token.balance(from).min(amount);
+        if clawed_back > 0 {
+            token
+                .try_clawback(&env.current_contract_address(), from, &clawed_back)
+                .map_err(|_| ContractError::TokenBurnFailed)?
+                .map_err(|_| ContractError::TokenBurnFailed)?;
         }
 
-        // Almacenar el certificado en Persistent Storage
-        // El uso de Persistent Storage evita state bloat en Instance Storage
-        env.storage().persistent().set(&key, &record);
+        Ok((clawed_back, amount - clawed_back))
+    }
+
+    /// Guarda el registro de revocación de un certificado y emite `CertificateRevokedEvent`
+    /// 
+    /// `cxo` es la tupla (recuperados, no recuperados) en unidades de CXO.
+    fn record_revocation(
+        env: &Env,
+        certificate_id: u32,
+        revoked_by: Address,
+        reason_hash: BytesN<32>,
+        co2e_kg_revoked: u128,
+        cxo: (i128, i128),
+    ) {
+        let (cxo_clawed_back, cxo_unrecovered) = cxo;
+        Self::write_persistent(env, &DataKey::Revocation(certificate_id), &RevocationRecord {
+            revoked_by: revoked_by.clone(),
+            reason_hash: reason_hash.clone(),
+            timestamp: env.ledger().timestamp(),
+            co2e_kg_revoked,
+            cxo_clawed_back,
+            cxo_unrecovered,
+        });
 
-        // Actualizar contadores globales
-        Self::increment_certificate_count(&env);
-        Self::add_co2e_to_total(&env, record.co2e_tons);
+        CertificateRevokedEvent {
+            certificate_id,
+            revoked_by,
+            reason_hash,
+            co2e_kg_revoked,
+            cxo_clawed_back,
+        }
+        .publish(env);
+    }
 
-        // Indexar el certificado por agricultor y verificador
-        Self::add_to_index(&env, record.farmer_address.clone(), certificate_id, true);
-        Self::add_to_index(&env, record.verifier_address.clone(), certificate_id, false);
+    /// Lee el estado de un certificado
+    /// 
+    /// Los certificados acuñados antes de existir el ciclo de vida no tienen
+    /// `Status` guardado y se consideran `Active` mientras exista su record.
+    fn read_status(env: &Env, certificate_id: u32) -> Option<CertificateStatus> {
+        env.storage().persistent().get(&DataKey::Status(certificate_id)).or_else(|| {
+            env.storage().persistent()
+                .has(&DataKey::Certificates(certificate_id))
+                .then_some(CertificateStatus::Active)
+        })
+    }
 
-        // ✅ ESTABLECER PROPIETARIO INICIAL: El agricultor es el propietario inicial del NFT
-        let owner_key = DataKey::CertificateOwner(certificate_id);
-        env.storage().persistent().set(&owner_key, &record.farmer_address);
-
-        // ✅ INVOCACIÓN CROSS-CONTRACT: Acuñar tokens CXO
-        if let Some(token_contract_id) = env.storage().instance().get(&DataKey::TokenContractId) {
-            // Convertir u128 a i128 para la llamada
-            let amount = record.co2e_tons as i128;
-            
-            // Invocar la función mint del contrato CarbonToken
-            // Crear los argumentos como un Vec<Val>
-            let mut args = soroban_sdk::Vec::new(&env);
-            args.push_back(record.farmer_address.clone().into_val(&env));
-            args.push_back(amount.into_val(&env));
-            let _: Result<(), soroban_sdk::Error> = env.invoke_contract(
-                &token_contract_id,
-                &soroban_sdk::symbol_short!("mint"),
-                args,
-            );
+    /// Verifica que un certificado esté activo
+    fn require_active(env: &Env, certificate_id: u32) -> Result<(), ContractError> {
+        match Self::read_status(env, certificate_id) {
+            Some(CertificateStatus::Active) => Ok(()),
+            Some(CertificateStatus::Revoked) => Err(ContractError::CertificateRevoked),
+            Some(_) => Err(ContractError::InvalidStatusTransition),
+            None => Err(ContractError::NotFound),
         }
+    }
 
-        // Obtener timestamp de la acuñación
-        let timestamp = env.ledger().timestamp();
+    /// Cambia el estado de un certificado aplicando la máquina de estados
+    /// 
+    /// # Errores
+    /// * `ContractError::NotFound` si el certificado no existe
+    /// * `ContractError::CertificateRevoked` si el certificado ya fue revocado
+    /// * `ContractError::InvalidStatusTransition` si la transición no está permitida
+    fn transition_status(env: &Env, certificate_id: u32, to: CertificateStatus) -> Result<(), ContractError> {
+        let from = Self::read_status(env, certificate_id).ok_or(ContractError::NotFound)?;
 
-        // ✅ EMITIR EVENTO: Notificar el acuñamiento del certificado
-        CertificateMintedEvent {
-            certificate_id,
-            farmer: record.farmer_address,
-            verifier: record.verifier_address,
-            tons_minted: record.co2e_tons,
-            timestamp,
+        let allowed = matches!(
+            (from, to),
+            (CertificateStatus::Pending, CertificateStatus::Active)
+                | (CertificateStatus::Pending, CertificateStatus::Revoked)
+                | (CertificateStatus::Active, CertificateStatus::Retired)
+                | (CertificateStatus::Active, CertificateStatus::Revoked)
+                | (CertificateStatus::Active, CertificateStatus::Expired)
+                | (CertificateStatus::Active, CertificateStatus::Split)
+                | (CertificateStatus::Active, CertificateStatus::Merged)
+        );
+        if !allowed {
+            return Err(match from {
+                CertificateStatus::Revoked => ContractError::CertificateRevoked,
+                _ => ContractError::InvalidStatusTransition,
+            });
         }
-        .publish(&env);
 
+        env.storage().persistent().set(&DataKey::Status(certificate_id), &to);
+        Self::extend_persistent_ttl(env, &DataKey::Status(certificate_id));
         Ok(())
     }
 
@@ -468,19 +3412,24 @@ impl CarbonCertifier {
     /// Función privada que actualiza el contador en Instance Storage
     /// Usa Instance Storage porque es un dato pequeño y permanente
     fn increment_certificate_count(env: &Env) {
+        Self::add_certificate_count(env, 1);
+    }
+
+    /// Suma `count` certificados al contador total (acuñación por lotes)
+    fn add_certificate_count(env: &Env, count: u32) {
         let key = DataKey::TotalCertificates;
         let current_count: u32 = env.storage().instance().get(&key).unwrap_or(0);
-        env.storage().instance().set(&key, &(current_count + 1));
+        env.storage().instance().set(&key, &(current_count + count));
     }
 
     /// Suma CO2e al total acumulado de créditos de carbono acuñados
     /// 
     /// Función privada que actualiza el contador de CO2e en Instance Storage
     /// Usa Instance Storage porque es un dato pequeño y permanente
-    fn add_co2e_to_total(env: &Env, co2e_tons: u128) {
+    fn add_co2e_to_total(env: &Env, co2e_kg: u128) {
         let key = DataKey::TotalCO2e;
         let current_total: u128 = env.storage().instance().get(&key).unwrap_or(0);
-        env.storage().instance().set(&key, &(current_total + co2e_tons));
+        env.storage().instance().set(&key, &(current_total + co2e_kg));
     }
 
     /// Decrementa el contador total de certificados acuñados
@@ -497,12 +3446,225 @@ impl CarbonCertifier {
     /// Resta CO2e del total acumulado de créditos de carbono acuñados
     /// 
     /// Función privada que actualiza el contador de CO2e en Instance Storage al quemar un certificado
-    fn subtract_co2e_from_total(env: &Env, co2e_tons: u128) {
+    fn subtract_co2e_from_total(env: &Env, co2e_kg: u128) {
         let key = DataKey::TotalCO2e;
         let current_total: u128 = env.storage().instance().get(&key).unwrap_or(0);
-        if current_total >= co2e_tons {
-            env.storage().instance().set(&key, &(current_total - co2e_tons));
+        if current_total >= co2e_kg {
+            env.storage().instance().set(&key, &(current_total - co2e_kg));
+        }
+    }
+
+    /// Emite y almacena un recibo de retiro permanente
+    /// 
+    /// Asigna el siguiente ID de recibo e indexa el recibo por beneficiario. Se
+    /// llama antes de reducir o eliminar el certificado, de cuyo tonelaje vigente
+    /// y retirado se toma el tonelaje original.
+    fn issue_receipt(
+        env: &Env,
+        certificate_id: u32,
+        record: &VerificationRecord,
+        retired_by: &Address,
+        co2e_kg: u128,
+        claim: RetirementClaim,
+    ) -> u32 {
+        let count_key = DataKey::TotalReceipts;
+        let receipt_id: u32 = env.storage().instance().get::<DataKey, u32>(&count_key).unwrap_or(0) + 1;
+        env.storage().instance().set(&count_key, &receipt_id);
+
+        // El tonelaje original suma lo vigente y lo retirado en retiros anteriores
+        let retired: u128 = env.storage().persistent()
+            .get(&DataKey::CertificateRetired(certificate_id))
+            .unwrap_or(0);
+
+        let receipt = RetirementReceipt {
+            receipt_id,
+            certificate_id,
+            retired_by: retired_by.clone(),
+            beneficiary: claim.beneficiary.clone(),
+            beneficiary_name: claim.beneficiary_name,
+            reason: claim.reason,
+            timestamp: env.ledger().timestamp(),
+            verifier_address: record.verifier_address.clone(),
+            farmer_address: record.farmer_address.clone(),
+            hectares_not_burned: record.hectares_not_burned,
+            metadata_hash: record.metadata_hash.clone(),
+            co2e_kg,
+            original_co2e_kg: record.co2e_kg + retired,
+        };
+        Self::write_persistent(env, &DataKey::RetirementReceipt(receipt_id), &receipt);
+
+        let list_key = DataKey::BeneficiaryReceiptList(claim.beneficiary);
+        let mut receipts = Self::read_cert_list(env, &list_key);
+        receipts.push_back(receipt_id);
+        Self::write_persistent(env, &list_key, &receipts);
+
+        receipt_id
+    }
+
+    /// Suma CO2e al total acumulado de créditos de carbono retirados
+    fn add_co2e_to_retired(env: &Env, co2e_kg: u128) {
+        let key = DataKey::TotalRetired;
+        let current_total: u128 = env.storage().instance().get(&key).unwrap_or(0);
+        env.storage().instance().set(&key, &(current_total + co2e_kg));
+    }
+
+    /// Almacena un certificado nuevo: record, propietario, estado e índices
+    /// 
+    /// También incrementa el contador de certificados; el contador de CO2e lo
+    /// actualiza el llamador.
+    fn insert_certificate(
+        env: &Env,
+        certificate_id: u32,
+        record: &VerificationRecord,
+        owner: &Address,
+        status: CertificateStatus,
+    ) {
+        Self::store_certificate(env, certificate_id, record, owner, status);
+
+        for list_key in Self::index_keys(record) {
+            Self::add_to_list(env, &list_key, certificate_id);
+        }
+
+        Self::increment_certificate_count(env);
+    }
+
+    /// Almacena record, propietario y estado de un certificado (sin índices ni contadores)
+    fn store_certificate(
+        env: &Env,
+        certificate_id: u32,
+        record: &VerificationRecord,
+        owner: &Address,
+        status: CertificateStatus,
+    ) {
+        // El uso de Persistent Storage evita state bloat en Instance Storage
+        env.storage().persistent().set(&DataKey::Certificates(certificate_id), record);
+        env.storage().persistent().set(&DataKey::CertificateOwner(certificate_id), owner);
+        env.storage().persistent().set(&DataKey::Status(certificate_id), &status);
+        Self::extend_certificate_ttl(env, certificate_id);
+    }
+
+    /// Lee un certificado renovando su TTL si existe
+    fn read_certificate(env: &Env, certificate_id: u32) -> Option<VerificationRecord> {
+        let key = DataKey::Certificates(certificate_id);
+        let record = env.storage().persistent().get(&key);
+        if record.is_some() {
+            Self::extend_persistent_ttl(env, &key);
+        }
+        record
+    }
+
+    /// Lee el propietario de un certificado renovando su TTL si existe
+    fn read_owner(env: &Env, certificate_id: u32) -> Option<Address> {
+        let key = DataKey::CertificateOwner(certificate_id);
+        let owner = env.storage().persistent().get(&key);
+        if owner.is_some() {
+            Self::extend_persistent_ttl(env, &key);
+        }
+        owner
+    }
+
+    /// Lee una entrada persistente renovando su TTL si existe
+    fn read_persistent<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> Option<V> {
+        let value = env.storage().persistent().get(key);
+        if value.is_some() {
+            Self::extend_persistent_ttl(env, key);
+        }
+        value
+    }
+
+    /// Escribe una entrada persistente y renueva su TTL
+    fn write_persistent<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+        env.storage().persistent().set(key, value);
+        Self::extend_persistent_ttl(env, key);
+    }
+
+    /// Lee una lista de índice renovando su TTL; una lista inexistente está vacía
+    fn read_cert_list(env: &Env, list_key: &DataKey) -> Vec<u32> {
+        match env.storage().persistent().get(list_key) {
+            Some(cert_list) => {
+                Self::extend_persistent_ttl(env, list_key);
+                cert_list
+            }
+            None => Vec::new(env),
+        }
+    }
+
+    /// Renueva el TTL del record, el propietario y el estado de un certificado (los que existan)
+    fn extend_certificate_ttl(env: &Env, certificate_id: u32) {
+        for key in [
+            DataKey::Certificates(certificate_id),
+            DataKey::CertificateOwner(certificate_id),
+            DataKey::Status(certificate_id),
+        ] {
+            if env.storage().persistent().has(&key) {
+                Self::extend_persistent_ttl(env, &key);
+            }
+        }
+    }
+
+    /// Renueva el TTL de una entrada persistente existente y el de la instancia
+    fn extend_persistent_ttl(env: &Env, key: &DataKey) {
+        let config = Self::get_ttl_config(env.clone());
+        env.storage().persistent().extend_ttl(key, config.threshold_ledgers, config.extend_to_ledgers);
+        env.storage().instance().extend_ttl(config.threshold_ledgers, config.extend_to_ledgers);
+    }
+
+    /// Renueva el TTL de la instancia del contrato (admin, contadores y configuración)
+    fn extend_instance_ttl(env: &Env) {
+        let config = Self::get_ttl_config(env.clone());
+        env.storage().instance().extend_ttl(config.threshold_ledgers, config.extend_to_ledgers);
+    }
+
+    /// Claves de los índices en los que aparece un certificado
+    /// (agricultor, verificador, vintage y proyecto)
+    fn index_keys(record: &VerificationRecord) -> [DataKey; 4] {
+        [
+            DataKey::FarmerCertList(record.farmer_address.clone()),
+            DataKey::VerifierCertList(record.verifier_address.clone()),
+            DataKey::VintageCertList(record.vintage),
+            DataKey::ProjectCertList(record.project_id.clone()),
+        ]
+    }
+
+    /// Asigna un ID libre a un certificado creado por el contrato y avanza
+    /// `NextCertificateId` más allá de él
+    fn allocate_certificate_id(env: &Env) -> Result<u32, ContractError> {
+        let id = Self::find_free_certificate_id(env)?;
+        env.storage().instance().set(&DataKey::NextCertificateId, &id.saturating_add(1));
+        Ok(id)
+    }
+
+    /// Busca el primer ID libre a partir de `NextCertificateId`
+    /// 
+    /// Salta los IDs ocupados por certificados acuñados con ID explícito, ya
+    /// retirados o reservados por una propuesta. Los IDs explícitos no mueven
+    /// la secuencia, de modo que un ID alto no agota los IDs asignables.
+    fn find_free_certificate_id(env: &Env) -> Result<u32, ContractError> {
+        let mut id: u32 = env.storage().instance().get(&DataKey::NextCertificateId).unwrap_or(1);
+        while env.storage().persistent().has(&DataKey::Certificates(id))
+            || env.storage().persistent().has(&DataKey::Status(id))
+            || env.storage().persistent().has(&DataKey::Proposal(id))
+        {
+            id = id.checked_add(1).ok_or(ContractError::CertificateIdsExhausted)?;
+        }
+        Ok(id)
+    }
+
+    /// Elimina un certificado: record, propietario, retiro parcial, enlace al padre e índices
+    /// 
+    /// También decrementa el contador de certificados; los contadores de CO2e
+    /// los actualiza el llamador.
+    fn remove_certificate(env: &Env, certificate_id: u32, record: &VerificationRecord) {
+        env.storage().persistent().remove(&DataKey::CertificateOwner(certificate_id));
+        env.storage().persistent().remove(&DataKey::Certificates(certificate_id));
+        env.storage().persistent().remove(&DataKey::CertificateRetired(certificate_id));
+        env.storage().persistent().remove(&DataKey::CertificateParent(certificate_id));
+
+        for list_key in Self::index_keys(record) {
+            Self::remove_from_list(env, &list_key, certificate_id);
         }
+
+        Self::decrement_certificate_count(env);
     }
 
     /// Elimina un ID de certificado de una lista de índice de manera eficiente
@@ -511,19 +3673,11 @@ impl CarbonCertifier {
     /// 
     /// # Argumentos
     /// * `env` - El entorno del contrato
-    /// * `actor_address` - Dirección del actor (agricultor o verificador)
+    /// * `list_key` - Clave del índice (ver `index_keys`)
     /// * `certificate_id` - ID del certificado a eliminar
-    /// * `is_farmer` - Si es true, elimina de FarmerCertList; si es false, de VerifierCertList
-    fn remove_from_index(env: &Env, actor_address: &Address, certificate_id: u32, is_farmer: bool) {
-        // Determinar la clave según el tipo de actor
-        let list_key = if is_farmer {
-            DataKey::FarmerCertList(actor_address.clone())
-        } else {
-            DataKey::VerifierCertList(actor_address.clone())
-        };
-
+    fn remove_from_list(env: &Env, list_key: &DataKey, certificate_id: u32) {
         // Obtener la lista actual (si existe)
-        if let Some(mut cert_list) = env.storage().persistent().get::<DataKey, Vec<u32>>(&list_key) {
+        if let Some(mut cert_list) = env.storage().persistent().get::<DataKey, Vec<u32>>(list_key) {
             // Buscar el índice del certificado en la lista
             let mut found_index: Option<u32> = None;
             for i in 0..cert_list.len() {
@@ -548,7 +3702,8 @@ impl CarbonCertifier {
                 cert_list.pop_back();
                 
                 // Guardar la lista actualizada
-                env.storage().persistent().set(&list_key, &cert_list);
+                env.storage().persistent().set(list_key, &cert_list);
+                Self::extend_persistent_ttl(env, list_key);
             }
         }
     }
@@ -562,39 +3717,76 @@ impl CarbonCertifier {
         env.storage().instance().get(&key).unwrap_or(0)
     }
 
-    /// Obtiene el total de toneladas de CO2e acuñadas
+    /// Obtiene el total de CO2e acuñado en kilogramos (unidad cruda)
     /// 
     /// # Retorna
-    /// `u128` - El total de toneladas de CO2e acuñadas
+    /// `u128` - El total de kilogramos de CO2e acuñados
     pub fn get_total_co2e(env: Env) -> u128 {
         let key = DataKey::TotalCO2e;
         env.storage().instance().get(&key).unwrap_or(0)
     }
 
-    /// Añade un certificado a la lista de un actor (agricultor o verificador)
+    /// Obtiene el total de CO2e acuñado en unidades crudas y legibles
+    /// 
+    /// # Retorna
+    /// `Co2eAmount` - Kilogramos, toneladas + kilogramos restantes y unidades de CXO
+    pub fn get_total_co2e_amount(env: Env) -> Co2eAmount {
+        Self::co2e_amount(Self::get_total_co2e(env))
+    }
+
+    /// Obtiene el CO2e de un certificado en unidades crudas y legibles
+    /// 
+    /// # Errores
+    /// * `ContractError::NotFound` si el certificado no existe
+    pub fn get_certificate_co2e(env: Env, certificate_id: u32) -> Result<Co2eAmount, ContractError> {
+        let record = Self::get_certificate_data(env, certificate_id)?;
+        Ok(Self::co2e_amount(record.co2e_kg))
+    }
+
+    /// Convierte kilogramos de CO2e a unidades mínimas de CXO (7 decimales)
+    /// 
+    /// Retorna `None` si el resultado no cabe en `i128`.
+    fn kg_to_cxo_units(co2e_kg: u128) -> Option<i128> {
+        let units = co2e_kg.checked_mul(CXO_UNITS_PER_KG)?;
+        i128::try_from(units).ok()
+    }
+
+    /// Construye la representación cruda y legible de una cantidad de CO2e
+    fn co2e_amount(co2e_kg: u128) -> Co2eAmount {
+        Co2eAmount {
+            kg: co2e_kg,
+            tons: co2e_kg / KG_PER_TON,
+            remainder_kg: (co2e_kg % KG_PER_TON) as u32,
+            cxo_units: Self::kg_to_cxo_units(co2e_kg).unwrap_or(i128::MAX),
+        }
+    }
+
+    /// Añade un certificado al final de una lista de índice
     /// 
     /// Función privada que actualiza los índices en Persistent Storage
     /// 
     /// # Argumentos
     /// * `env` - El entorno del contrato
-    /// * `actor_address` - La dirección del actor (farmer o verifier)
+    /// * `list_key` - Clave del índice (ver `index_keys`)
     /// * `certificate_id` - El ID del certificado a añadir
-    /// * `is_farmer` - true si es agricultor, false si es verificador
-    fn add_to_index(env: &Env, actor_address: Address, certificate_id: u32, is_farmer: bool) {
-        let key = if is_farmer {
-            DataKey::FarmerCertList(actor_address)
-        } else {
-            DataKey::VerifierCertList(actor_address)
-        };
-
+    fn add_to_list(env: &Env, list_key: &DataKey, certificate_id: u32) {
         // Obtener la lista existente o crear una nueva
-        let mut cert_list: Vec<u32> = env.storage().persistent().get(&key).unwrap_or(Vec::new(&env));
+        let mut cert_list: Vec<u32> = Self::read_cert_list(env, list_key);
         
         // Añadir el nuevo ID al final de la lista
         cert_list.push_back(certificate_id);
         
         // Guardar la lista actualizada en Persistent Storage
-        env.storage().persistent().set(&key, &cert_list);
+        env.storage().persistent().set(list_key, &cert_list);
+        Self::extend_persistent_ttl(env, list_key);
+    }
+
+    /// Añade varios certificados al final de una lista de índice con una sola escritura
+    fn append_to_list(env: &Env, list_key: &DataKey, certificate_ids: &Vec<u32>) {
+        let mut cert_list: Vec<u32> = Self::read_cert_list(env, list_key);
+        cert_list.append(certificate_ids);
+        env.storage().persistent().set(list_key, &cert_list);
+        Self::extend_persistent_ttl(env, list_key);
     }
 
     /// Lista los IDs de certificados asociados a un agricultor específico (con paginación y ordenamiento)
@@ -604,7 +3796,7 @@ impl CarbonCertifier {
     /// * `farmer_address` - La dirección del agricultor
     /// * `offset` - El punto de inicio de la paginación (0-indexed)
     /// * `limit` - El número máximo de IDs a devolver
-    /// * `sort_by` - Criterio de ordenamiento (Co2eTons, Hectares, CertificateId)
+    /// * `sort_by` - Criterio de ordenamiento (Co2eKg, Hectares, CertificateId)
     /// * `is_descending` - Si true, orden descendente; si false, orden ascendente
     /// 
     /// # Retorna
@@ -618,7 +3810,7 @@ impl CarbonCertifier {
         is_descending: bool,
     ) -> (Vec<u32>, u32) {
         let key = DataKey::FarmerCertList(farmer_address);
-        let all_certs = env.storage().persistent().get(&key).unwrap_or(Vec::new(&env));
+        let all_certs = Self::read_cert_list(&env, &key);
         
         // Obtener y ordenar los registros completos
         let sorted_ids = Self::sort_certificates(&env, &all_certs, sort_by, is_descending);
@@ -641,10 +3833,9 @@ impl CarbonCertifier {
         let mut pairs: Vec<(u32, u128)> = Vec::new(env);
         
         for id in cert_ids.iter() {
-            let cert_key = DataKey::Certificates(id);
-            if let Some(record) = env.storage().persistent().get::<DataKey, VerificationRecord>(&cert_key) {
+            if let Some(record) = Self::read_certificate(env, id) {
                 let sort_value = match sort_by {
-                    SortBy::Co2eTons => record.co2e_tons,
+                    SortBy::Co2eKg => record.co2e_kg,
                     SortBy::Hectares => record.hectares_not_burned as u128,
                     SortBy::CertificateId => id as u128,
                 };
@@ -697,18 +3888,85 @@ impl CarbonCertifier {
         limit: u32,
     ) -> (Vec<u32>, u32) {
         let key = DataKey::VerifierCertList(verifier_address);
-        let all_certs = env.storage().persistent().get(&key).unwrap_or(Vec::new(&env));
+        let all_certs = Self::read_cert_list(&env, &key);
         
         Self::paginate_cert_list(&env, &all_certs, offset, limit)
     }
     
+    /// Lista los IDs de certificados de un año de cosecha (con paginación)
+    /// 
+    /// # Argumentos
+    /// * `env` - El entorno del contrato
+    /// * `vintage` - Año de cosecha
+    /// * `offset` - El punto de inicio de la paginación (0-indexed)
+    /// * `limit` - El número máximo de IDs a devolver
+    /// 
+    /// # Retorna
+    /// `(Vec<u32>, u32)` - Tupla que contiene (lista paginada de IDs, total de certificados)
+    pub fn list_certificates_by_vintage(env: Env, vintage: u32, offset: u32, limit: u32) -> (Vec<u32>, u32) {
+        let key = DataKey::VintageCertList(vintage);
+        let all_certs = Self::read_cert_list(&env, &key);
+
+        Self::paginate_cert_list(&env, &all_certs, offset, limit)
+    }
+
+    /// Lista los IDs de certificados de un proyecto / ingenio (con paginación)
+    /// 
+    /// # Argumentos
+    /// * `env` - El entorno del contrato
+    /// * `project_id` - Identificador del proyecto / ingenio
+    /// * `offset` - El punto de inicio de la paginación (0-indexed)
+    /// * `limit` - El número máximo de IDs a devolver
+    /// 
+    /// # Retorna
+    /// `(Vec<u32>, u32)` - Tupla que contiene (lista paginada de IDs, total de certificados)
+    pub fn list_certificates_by_project(env: Env, project_id: Symbol, offset: u32, limit: u32) -> (Vec<u32>, u32) {
+        let key = DataKey::ProjectCertList(project_id);
+        let all_certs = Self::read_cert_list(&env, &key);
+
+        Self::paginate_cert_list(&env, &all_certs, offset, limit)
+    }
+
+    /// Filtra los certificados de un proyecto por año de cosecha (con paginación)
+    /// 
+    /// # Argumentos
+    /// * `env` - El entorno del contrato
+    /// * `project_id` - Identificador del proyecto / ingenio
+    /// * `vintage` - Año de cosecha
+    /// * `offset` - El punto de inicio de la paginación (0-indexed)
+    /// * `limit` - El número máximo de IDs a devolver
+    /// 
+    /// # Retorna
+    /// `(Vec<u32>, u32)` - Tupla que contiene (IDs filtrados y paginados, total de certificados filtrados)
+    pub fn filter_project_by_vintage(
+        env: Env,
+        project_id: Symbol,
+        vintage: u32,
+        offset: u32,
+        limit: u32,
+    ) -> (Vec<u32>, u32) {
+        let key = DataKey::ProjectCertList(project_id);
+        let all_certs: Vec<u32> = Self::read_cert_list(&env, &key);
+
+        let mut filtered_ids = Vec::new(&env);
+        for id in all_certs.iter() {
+            if let Some(record) = Self::read_certificate(&env, id) {
+                if record.vintage == vintage {
+                    filtered_ids.push_back(id);
+                }
+            }
+        }
+
+        Self::paginate_cert_list(&env, &filtered_ids, offset, limit)
+    }
+
     /// Filtra certificados de un agricultor por rango de CO2e (con paginación)
     /// 
     /// # Argumentos
     /// * `env` - El entorno del contrato
     /// * `farmer_address` - La dirección del agricultor
-    /// * `min_tons` - Toneladas mínimas de CO2e (inclusive)
-    /// * `max_tons` - Toneladas máximas de CO2e (inclusive)
+    /// * `min_kg` - Kilogramos mínimos de CO2e (inclusive)
+    /// * `max_kg` - Kilogramos máximos de CO2e (inclusive)
     /// * `offset` - El punto de inicio de la paginación (0-indexed)
     /// * `limit` - El número máximo de IDs a devolver
     /// 
@@ -717,28 +3975,95 @@ impl CarbonCertifier {
     pub fn filter_by_co2e_range(
         env: Env,
         farmer_address: Address,
-        min_tons: u128,
-        max_tons: u128,
+        min_kg: u128,
+        max_kg: u128,
         offset: u32,
         limit: u32,
     ) -> (Vec<u32>, u32) {
         let key = DataKey::FarmerCertList(farmer_address);
-        let all_certs = env.storage().persistent().get(&key).unwrap_or(Vec::new(&env));
+        let all_certs = Self::read_cert_list(&env, &key);
         
         // Filtrar certificados por rango de CO2e
-        let filtered_ids = Self::filter_by_co2e(&env, &all_certs, min_tons, max_tons);
+        let filtered_ids = Self::filter_by_co2e(&env, &all_certs, min_kg, max_kg);
         
         Self::paginate_cert_list(&env, &filtered_ids, offset, limit)
     }
     
+    /// Lista los certificados de un agricultor que están en un estado dado (con paginación)
+    /// 
+    /// Los certificados retirados por completo ya no forman parte de los índices;
+    /// sus retiros se consultan mediante los recibos.
+    /// 
+    /// # Argumentos
+    /// * `env` - El entorno del contrato
+    /// * `farmer_address` - La dirección del agricultor
+    /// * `status` - Estado del ciclo de vida a filtrar
+    /// * `offset` - El punto de inicio de la paginación (0-indexed)
+    /// * `limit` - El número máximo de IDs a devolver
+    /// 
+    /// # Retorna
+    /// `(Vec<u32>, u32)` - Tupla que contiene (IDs filtrados y paginados, total de certificados filtrados)
+    pub fn list_farmer_certs_by_status(
+        env: Env,
+        farmer_address: Address,
+        status: CertificateStatus,
+        offset: u32,
+        limit: u32,
+    ) -> (Vec<u32>, u32) {
+        let key = DataKey::FarmerCertList(farmer_address);
+        let all_certs = Self::read_cert_list(&env, &key);
+
+        let filtered_ids = Self::filter_by_status(&env, &all_certs, status);
+
+        Self::paginate_cert_list(&env, &filtered_ids, offset, limit)
+    }
+
+    /// Lista los certificados de un verificador que están en un estado dado (con paginación)
+    /// 
+    /// # Argumentos
+    /// * `env` - El entorno del contrato
+    /// * `verifier_address` - La dirección del verificador
+    /// * `status` - Estado del ciclo de vida a filtrar
+    /// * `offset` - El punto de inicio de la paginación (0-indexed)
+    /// * `limit` - El número máximo de IDs a devolver
+    /// 
+    /// # Retorna
+    /// `(Vec<u32>, u32)` - Tupla que contiene (IDs filtrados y paginados, total de certificados filtrados)
+    pub fn list_verifier_certs_by_status(
+        env: Env,
+        verifier_address: Address,
+        status: CertificateStatus,
+        offset: u32,
+        limit: u32,
+    ) -> (Vec<u32>, u32) {
+        let key = DataKey::VerifierCertList(verifier_address);
+        let all_certs = Self::read_cert_list(&env, &key);
+
+        let filtered_ids = Self::filter_by_status(&env, &all_certs, status);
+
+        Self::paginate_cert_list(&env, &filtered_ids, offset, limit)
+    }
+
+    /// Función privada para filtrar certificados por estado
+    fn filter_by_status(env: &Env, cert_ids: &Vec<u32>, status: CertificateStatus) -> Vec<u32> {
+        let mut filtered = Vec::new(env);
+
+        for id in cert_ids.iter() {
+            if Self::read_status(env, id) == Some(status) {
+                filtered.push_back(id);
+            }
+        }
+
+        filtered
+    }
+
     /// Función privada para filtrar certificados por rango de CO2e
-    fn filter_by_co2e(env: &Env, cert_ids: &Vec<u32>, min_tons: u128, max_tons: u128) -> Vec<u32> {
+    fn filter_by_co2e(env: &Env, cert_ids: &Vec<u32>, min_kg: u128, max_kg: u128) -> Vec<u32> {
         let mut filtered = Vec::new(env);
         
         for id in cert_ids.iter() {
-            let cert_key = DataKey::Certificates(id);
-            if let Some(record) = env.storage().persistent().get::<DataKey, VerificationRecord>(&cert_key) {
-                if record.co2e_tons >= min_tons && record.co2e_tons <= max_tons {
+            if let Some(record) = Self::read_certificate(env, id) {
+                if record.co2e_kg >= min_kg && record.co2e_kg <= max_kg {
                     filtered.push_back(id);
                 }
             }
@@ -778,3 +4103,94 @@ impl CarbonCertifier {
     }
 }
 
+/// Freno de emergencia: mientras está pausado se bloquean la acuñación,
+/// transferencia, quema y retiro de certificados; las consultas siguen disponibles.
+#[contractimpl]
+impl Pausable for CarbonCertifier {
+    /// Indica si el contrato está pausado
+    fn paused(e: &Env) -> bool {
+        pausable::paused(e)
+    }
+
+    /// Pausa el contrato
+    /// 
+    /// # Errores
+    /// * `AccessControlError::Unauthorized` si `caller` no tiene el rol `pauser`
+    /// * `PausableError::EnforcedPause` si ya está pausado
+    /// 
+    /// # Emite
+    /// * Evento `paused` de `stellar-contract-utils`
+    fn pause(e: &Env, caller: Address) {
+        Self::ensure_role(e, &caller, PAUSER_ROLE);
+        pausable::pause(e);
+    }
+
+    /// Reanuda el contrato
+    /// 
+    /// # Errores
+    /// * `AccessControlError::Unauthorized` si `caller` no tiene el rol `pauser`
+    /// * `PausableError::ExpectedPause` si no está pausado
+    /// 
+    /// # Emite
+    /// * Evento `unpaused` de `stellar-contract-utils`
+    fn unpause(e: &Env, caller: Address) {
+        Self::ensure_role(e, &caller, PAUSER_ROLE);
+        pausable::unpause(e);
+    }
+}
+
+/// Admin y roles (`registry_manager`, `config_manager`, `verifier`, `revoker`,
+/// `pauser`, `upgrader`) gestionados con `stellar-access`. El traspaso del admin es
+/// en dos pasos (`transfer_admin_role` y `accept_admin_transfer`) y `renounce_admin` es
+/// irreversible. Como la exporta esta interfaz, `renounce_admin` no tiene un envoltorio
+/// propio junto a `propose_admin` y `accept_admin`, pero se invoca igual que en CarbonToken.
+#[default_impl]
+#[contractimpl]
+impl AccessControl for CarbonCertifier {
+    /// Propone traspasar la administración; con `live_until_ledger = 0` cancela
+    /// el traspaso pendiente (ver `propose_admin` y `cancel_admin_transfer`)
+    fn transfer_admin_role(e: &Env, new_admin: Address, live_until_ledger: u32) {
+        let admin = access_control::get_admin(e)
+            .unwrap_or_else(|| panic_with_error!(e, ContractError::NotAuthorized));
+        let result = if live_until_ledger == 0 {
+            Self::cancel_pending_admin(e, admin)
+        } else {
+            Self::start_admin_transfer(e, admin, new_admin, live_until_ledger)
+        };
+        if let Err(error) = result {
+            panic_with_error!(e, error);
+        }
+    }
+
+    /// Acepta el traspaso de administración pendiente (ver `accept_admin`)
+    fn accept_admin_transfer(e: &Env) {
+        if let Err(error) = Self::complete_admin_transfer(e) {
+            panic_with_error!(e, error);
+        }
+    }
+
+    /// Renuncia de forma definitiva a la administración del contrato
+    /// 
+    /// Irreversible: las funciones de administrador dejan de estar disponibles,
+    /// se descarta cualquier traspaso pendiente y el contrato no puede volver a
+    /// inicializarse. El admin pierde el rol `upgrader`; sus demás roles siguen
+    /// vigentes hasta que los renuncie con `renounce_role`.
+    /// 
+    /// # Emite
+    /// * `AdminRenouncedEvent`
+    fn renounce_admin(e: &Env) {
+        let admin = access_control::get_admin(e)
+            .unwrap_or_else(|| panic_with_error!(e, ContractError::NotAuthorized));
+
+        // ✅ ROLES: Sin admin nadie podría retirar `upgrader`; se renuncia junto con la administración
+        let upgrader = Symbol::new(e, UPGRADER_ROLE);
+        if access_control::has_role(e, &admin, &upgrader).is_some() {
+            access_control::revoke_role_no_auth(e, &admin, &admin, &upgrader);
+        }
+        // ✅ TRASPASO: Un traspaso pendiente se descarta antes de renunciar
+        e.storage().temporary().remove(&AccessControlStorageKey::PendingAdmin);
+        access_control::renounce_admin(e);
+
+        AdminRenouncedEvent { admin }.publish(e);
+    }
+}
@@ -1,6 +1,7 @@
 #![no_std]
 
 mod contract;
+pub mod token;
 
 #[cfg(test)]
 mod test;
@@ -1,9 +1,80 @@
 use super::*;
 use soroban_sdk::{
-    testutils::Address as _, 
-    BytesN, Env, Address
+    testutils::{storage::Persistent as _, Address as _, Events as _, Ledger as _},
+    map, symbol_short, vec, BytesN, Env, Address, IntoVal, InvokeError, String, Symbol, Vec
 };
+use stellar_access::access_control::AccessControlError;
 use crate::contract::SortBy;
+use carbon_token::{CarbonToken, CarbonTokenClient};
+
+/// Factor de emisión holgado para las pruebas: 50 t CO2e por hectárea
+const TEST_KG_CO2E_PER_HECTARE: u128 = 50_000;
+
+/// Inicializa el contrato con un admin nuevo, despliega un CarbonToken con el
+/// mismo admin que otorga el rol de minter al certificador, registra
+/// `verifier_address` como verificador activo y configura el factor de emisión
+/// de `morelos`/2024. Retorna (admin, dirección del token).
+fn register_verifier(env: &Env, client: &CarbonCertifierClient, verifier_address: &Address) -> (Address, Address) {
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+    
+    let token_id = env.register(CarbonToken, ());
+    let token_client = CarbonTokenClient::new(env, &token_id);
+    token_client.initialize(&admin);
+    token_client.grant_minter(&client.address);
+    client.set_token_contract_id(&admin, &token_id);
+    
+    client.add_verifier(
+        &admin,
+        verifier_address,
+        &String::from_str(env, "Ingenio Emiliano Zapata"),
+        &BytesN::from_array(env, &[7u8; 32]),
+    );
+    client.set_emission_factor(&admin, &0, &symbol_short!("morelos"), &2024, &TEST_KG_CO2E_PER_HECTARE);
+    (admin, token_id)
+}
+
+/// Registra `parcel_id` a nombre de `farmer` con 1,000 ha de superficie
+fn register_parcel(env: &Env, client: &CarbonCertifierClient, parcel_id: u32, farmer: &Address) {
+    client.register_parcel(
+        &client.get_admin().unwrap(),
+        &parcel_id,
+        farmer,
+        &BytesN::from_array(env, &[3u8; 32]),
+        &1_000,
+    );
+}
+
+/// Record de 10 ha en `morelos`/2024 para la parcela dada; las variantes se
+/// construyen con `VerificationRecord { campo, ..record(...) }`
+fn record(env: &Env, verifier: &Address, farmer: &Address, parcel_id: u32, co2e_kg: u128) -> VerificationRecord {
+    VerificationRecord {
+        verifier_address: verifier.clone(),
+        farmer_address: farmer.clone(),
+        hectares_not_burned: 10,
+        co2e_kg,
+        metadata_hash: BytesN::from_array(env, &[0u8; 32]),
+        region: symbol_short!("morelos"),
+        season: 2024,
+        methodology_version: 0,
+        parcel_id,
+        vintage: 2024,
+        monitoring_start: 1_704_067_200,
+        monitoring_end: 1_735_689_599,
+        project_id: symbol_short!("ezapata"),
+        municipality: String::from_str(env, "Xochitepec"),
+    }
+}
+
+/// Nombre del beneficiario y motivo con que se emiten los recibos de retiro
+fn claim(env: &Env) -> (String, String) {
+    (String::from_str(env, "Grupo Modelo"), String::from_str(env, "Compensación 2024"))
+}
+
+/// Error con el que `stellar-access` rechaza a quien no tiene el rol requerido
+fn unauthorized() -> Result<ContractError, InvokeError> {
+    Err(InvokeError::Contract(AccessControlError::Unauthorized as u32))
+}
 
 #[test]
 fn test_verification_record_structure() {
@@ -19,12 +90,21 @@ fn test_verification_record_structure() {
         verifier_address: verifier_address.clone(),
         farmer_address: farmer_address.clone(),
         hectares_not_burned: 10,
-        co2e_tons: 100,
+        co2e_kg: 100,
         metadata_hash,
+        region: symbol_short!("morelos"),
+        season: 2024,
+        methodology_version: 0,
+        parcel_id: 1,
+        vintage: 2024,
+        monitoring_start: 1_704_067_200,
+        monitoring_end: 1_735_689_599,
+        project_id: symbol_short!("ezapata"),
+        municipality: String::from_str(&env, "Xochitepec"),
     };
     
     assert_eq!(record.hectares_not_burned, 10);
-    assert_eq!(record.co2e_tons, 100);
+    assert_eq!(record.co2e_kg, 100);
     assert_eq!(record.verifier_address, verifier_address);
     assert_eq!(record.farmer_address, farmer_address);
 }
@@ -68,16 +148,11 @@ fn test_get_certificate_data_success() {
     let client = CarbonCertifierClient::new(&env, &contract_id);
     
     let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
     let farmer_address = Address::generate(&env);
-    let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
+    register_parcel(&env, &client, 1, &farmer_address);
     
-    let record = VerificationRecord {
-        verifier_address: verifier_address.clone(),
-        farmer_address: farmer_address.clone(),
-        hectares_not_burned: 10,
-        co2e_tons: 100,
-        metadata_hash,
-    };
+    let record = record(&env, &verifier_address, &farmer_address, 1, 100);
     
     // Acuñar el certificado primero
     client.mint_certificate(&1, &record);
@@ -88,7 +163,7 @@ fn test_get_certificate_data_success() {
     assert_eq!(retrieved_record.verifier_address, verifier_address);
     assert_eq!(retrieved_record.farmer_address, farmer_address);
     assert_eq!(retrieved_record.hectares_not_burned, 10);
-    assert_eq!(retrieved_record.co2e_tons, 100);
+    assert_eq!(retrieved_record.co2e_kg, 100);
 }
 
 // ============================================================================
@@ -104,15 +179,12 @@ fn test_mint_certificate_success() {
     let client = CarbonCertifierClient::new(&env, &contract_id);
     
     let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
     let farmer_address = Address::generate(&env);
-    let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
-    
+    register_parcel(&env, &client, 1, &farmer_address);
     let record = VerificationRecord {
-        verifier_address: verifier_address.clone(),
-        farmer_address: farmer_address.clone(),
         hectares_not_burned: 15,
-        co2e_tons: 150,
-        metadata_hash,
+        ..record(&env, &verifier_address, &farmer_address, 1, 150)
     };
     
     // Acuñar el certificado
@@ -121,7 +193,7 @@ fn test_mint_certificate_success() {
     // Verificar que se almacenó correctamente
     let retrieved = client.get_certificate_data(&1);
     assert_eq!(retrieved.hectares_not_burned, 15);
-    assert_eq!(retrieved.co2e_tons, 150);
+    assert_eq!(retrieved.co2e_kg, 150);
     assert_eq!(retrieved.verifier_address, verifier_address);
     assert_eq!(retrieved.farmer_address, farmer_address);
 }
@@ -135,16 +207,11 @@ fn test_mint_certificate_already_exists() {
     let client = CarbonCertifierClient::new(&env, &contract_id);
     
     let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
     let farmer_address = Address::generate(&env);
-    let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
+    register_parcel(&env, &client, 1, &farmer_address);
     
-    let record = VerificationRecord {
-        verifier_address: verifier_address.clone(),
-        farmer_address: farmer_address.clone(),
-        hectares_not_burned: 10,
-        co2e_tons: 100,
-        metadata_hash,
-    };
+    let record = record(&env, &verifier_address, &farmer_address, 1, 100);
     
     // Acuñar el certificado la primera vez
     client.mint_certificate(&1, &record);
@@ -165,17 +232,15 @@ fn test_mint_certificate_unauthorized() {
     let verifier_address = Address::generate(&env);
     let farmer_address = Address::generate(&env);
     let _unauthorized_address = Address::generate(&env);
-    let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
     
     // Crear un record con una dirección de verificador no autorizada
-    let record = VerificationRecord {
-        verifier_address: verifier_address.clone(),
-        farmer_address: farmer_address.clone(),
-        hectares_not_burned: 10,
-        co2e_tons: 100,
-        metadata_hash,
-    };
+    let record = record(&env, &verifier_address, &farmer_address, 1, 100);
     
+    // Registrar el verificador con autorización simulada y luego limpiarla
+    env.mock_all_auths();
+    register_verifier(&env, &client, &verifier_address);
+    env.set_auths(&[]);
+
     // NO configurar mock auth para verifier_address
     // Esto significa que require_auth() fallará
     
@@ -199,17 +264,15 @@ fn test_multiple_certificates() {
     let client = CarbonCertifierClient::new(&env, &contract_id);
     
     let verifier_address = Address::generate(&env);
-    let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
+    register_verifier(&env, &client, &verifier_address);
     
     // Crear múltiples certificados con diferentes IDs
     for i in 1..=5 {
         let farmer_address = Address::generate(&env);
+        register_parcel(&env, &client, i, &farmer_address);
         let record = VerificationRecord {
-            verifier_address: verifier_address.clone(),
-            farmer_address,
             hectares_not_burned: i * 10,
-            co2e_tons: (i * 100) as u128,
-            metadata_hash: metadata_hash.clone(),
+            ..record(&env, &verifier_address, &farmer_address, i, (i * 100) as u128)
         };
         
         client.mint_certificate(&i, &record);
@@ -219,7 +282,7 @@ fn test_multiple_certificates() {
     for i in 1..=5 {
         let record = client.get_certificate_data(&i);
         assert_eq!(record.hectares_not_burned, i * 10);
-        assert_eq!(record.co2e_tons, (i * 100) as u128);
+        assert_eq!(record.co2e_kg, (i * 100) as u128);
     }
 }
 
@@ -232,15 +295,14 @@ fn test_certificate_persistent_storage() {
     let client = CarbonCertifierClient::new(&env, &contract_id);
     
     let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
     let farmer_address = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_address);
     let metadata_hash = BytesN::from_array(&env, &[99u8; 32]);
-    
     let record = VerificationRecord {
-        verifier_address: verifier_address.clone(),
-        farmer_address: farmer_address.clone(),
         hectares_not_burned: 20,
-        co2e_tons: 200,
-        metadata_hash,
+        metadata_hash: metadata_hash.clone(),
+        ..record(&env, &verifier_address, &farmer_address, 1, 200)
     };
     
     // Acuñar y verificar
@@ -252,7 +314,7 @@ fn test_certificate_persistent_storage() {
     
     // Verificar que los datos persisten
     assert_eq!(retrieved.hectares_not_burned, 20);
-    assert_eq!(retrieved.co2e_tons, 200);
+    assert_eq!(retrieved.co2e_kg, 200);
 }
 
 // ============================================================================
@@ -290,7 +352,7 @@ fn test_counters_increment_on_mint() {
     let client = CarbonCertifierClient::new(&env, &contract_id);
     
     let verifier_address = Address::generate(&env);
-    let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
+    register_verifier(&env, &client, &verifier_address);
     
     // Verificar que comienza en cero
     assert_eq!(client.get_total_certificates(), 0);
@@ -298,13 +360,8 @@ fn test_counters_increment_on_mint() {
     
     // Acuñar el primer certificado
     let farmer1 = Address::generate(&env);
-    let record1 = VerificationRecord {
-        verifier_address: verifier_address.clone(),
-        farmer_address: farmer1,
-        hectares_not_burned: 10,
-        co2e_tons: 100,
-        metadata_hash: metadata_hash.clone(),
-    };
+    register_parcel(&env, &client, 1, &farmer1);
+    let record1 = record(&env, &verifier_address, &farmer1, 1, 100);
     client.mint_certificate(&1, &record1);
     
     // Verificar que el contador se incrementó
@@ -313,12 +370,10 @@ fn test_counters_increment_on_mint() {
     
     // Acuñar el segundo certificado
     let farmer2 = Address::generate(&env);
+    register_parcel(&env, &client, 2, &farmer2);
     let record2 = VerificationRecord {
-        verifier_address: verifier_address.clone(),
-        farmer_address: farmer2,
         hectares_not_burned: 15,
-        co2e_tons: 150,
-        metadata_hash: metadata_hash.clone(),
+        ..record(&env, &verifier_address, &farmer2, 2, 150)
     };
     client.mint_certificate(&2, &record2);
     
@@ -336,22 +391,19 @@ fn test_counters_accumulate_multiple_mints() {
     let client = CarbonCertifierClient::new(&env, &contract_id);
     
     let verifier_address = Address::generate(&env);
-    let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
+    register_verifier(&env, &client, &verifier_address);
     
     let mut total_co2e_expected: u128 = 0;
     
     // Acuñar 5 certificados
     for i in 1..=5 {
         let farmer_address = Address::generate(&env);
+        register_parcel(&env, &client, i, &farmer_address);
         let co2e_amount = (i * 50) as u128;
         total_co2e_expected += co2e_amount;
-        
         let record = VerificationRecord {
-            verifier_address: verifier_address.clone(),
-            farmer_address,
             hectares_not_burned: i * 10,
-            co2e_tons: co2e_amount,
-            metadata_hash: metadata_hash.clone(),
+            ..record(&env, &verifier_address, &farmer_address, i, co2e_amount)
         };
         
         client.mint_certificate(&i, &record);
@@ -375,16 +427,14 @@ fn test_counters_persistent_across_queries() {
     let client = CarbonCertifierClient::new(&env, &contract_id);
     
     let verifier_address = Address::generate(&env);
-    let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
+    register_verifier(&env, &client, &verifier_address);
     
     // Acuñar un certificado
     let farmer = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer);
     let record = VerificationRecord {
-        verifier_address: verifier_address.clone(),
-        farmer_address: farmer,
         hectares_not_burned: 20,
-        co2e_tons: 200,
-        metadata_hash,
+        ..record(&env, &verifier_address, &farmer, 1, 200)
     };
     client.mint_certificate(&1, &record);
     
@@ -436,8 +486,9 @@ fn test_certificates_indexed_by_actor() {
     let client = CarbonCertifierClient::new(&env, &contract_id);
     
     let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
     let farmer_address = Address::generate(&env);
-    let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
+    register_parcel(&env, &client, 1, &farmer_address);
     
     // Verificar que ambas listas comienzan vacías
     let (farmer_certs, _) = client.list_certificates_by_farmer(&farmer_address, &0, &10, &SortBy::CertificateId, &false);
@@ -446,13 +497,7 @@ fn test_certificates_indexed_by_actor() {
     assert_eq!(verifier_certs.len(), 0);
     
     // Acuñar un certificado
-    let record = VerificationRecord {
-        verifier_address: verifier_address.clone(),
-        farmer_address: farmer_address.clone(),
-        hectares_not_burned: 10,
-        co2e_tons: 100,
-        metadata_hash,
-    };
+    let record = record(&env, &verifier_address, &farmer_address, 1, 100);
     client.mint_certificate(&1, &record);
     
     // Verificar que el certificado aparece en ambas listas
@@ -476,17 +521,15 @@ fn test_multiple_certificates_for_same_actor() {
     let client = CarbonCertifierClient::new(&env, &contract_id);
     
     let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
     let farmer_address = Address::generate(&env);
-    let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
     
     // Acuñar 3 certificados para el mismo agricultor
     for i in 1..=3 {
+        register_parcel(&env, &client, i, &farmer_address);
         let record = VerificationRecord {
-            verifier_address: verifier_address.clone(),
-            farmer_address: farmer_address.clone(),
             hectares_not_burned: i * 10,
-            co2e_tons: (i * 100) as u128,
-            metadata_hash: metadata_hash.clone(),
+            ..record(&env, &verifier_address, &farmer_address, i, (i * 100) as u128)
         };
         client.mint_certificate(&i, &record);
     }
@@ -514,30 +557,26 @@ fn test_certificates_isolated_by_actor() {
     let client = CarbonCertifierClient::new(&env, &contract_id);
     
     let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
     let farmer_a = Address::generate(&env);
     let farmer_b = Address::generate(&env);
-    let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
     
     // Acuñar 2 certificados para agricultor A
     for i in 1..=2 {
+        register_parcel(&env, &client, i, &farmer_a);
         let record = VerificationRecord {
-            verifier_address: verifier_address.clone(),
-            farmer_address: farmer_a.clone(),
             hectares_not_burned: i * 10,
-            co2e_tons: (i * 100) as u128,
-            metadata_hash: metadata_hash.clone(),
+            ..record(&env, &verifier_address, &farmer_a, i, (i * 100) as u128)
         };
         client.mint_certificate(&i, &record);
     }
     
     // Acuñar 2 certificados para agricultor B
     for i in 3..=4 {
+        register_parcel(&env, &client, i, &farmer_b);
         let record = VerificationRecord {
-            verifier_address: verifier_address.clone(),
-            farmer_address: farmer_b.clone(),
             hectares_not_burned: i * 10,
-            co2e_tons: (i * 100) as u128,
-            metadata_hash: metadata_hash.clone(),
+            ..record(&env, &verifier_address, &farmer_b, i, (i * 100) as u128)
         };
         client.mint_certificate(&i, &record);
     }
@@ -574,17 +613,12 @@ fn test_mint_certificate_invalid_co2e_zero() {
     let client = CarbonCertifierClient::new(&env, &contract_id);
     
     let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
     let farmer_address = Address::generate(&env);
-    let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
+    register_parcel(&env, &client, 1, &farmer_address);
     
-    // Intentar acuñar con co2e_tons = 0 (inválido)
-    let record = VerificationRecord {
-        verifier_address: verifier_address.clone(),
-        farmer_address: farmer_address.clone(),
-        hectares_not_burned: 10,
-        co2e_tons: 0, // ❌ Inválido
-        metadata_hash,
-    };
+    // Intentar acuñar con co2e_kg = 0 (inválido)
+    let record = record(&env, &verifier_address, &farmer_address, 1, 0);
     
     let result = client.try_mint_certificate(&1, &record);
     
@@ -601,16 +635,14 @@ fn test_mint_certificate_invalid_hectares_zero() {
     let client = CarbonCertifierClient::new(&env, &contract_id);
     
     let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
     let farmer_address = Address::generate(&env);
-    let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
+    register_parcel(&env, &client, 1, &farmer_address);
     
     // Intentar acuñar con hectares_not_burned = 0 (inválido)
     let record = VerificationRecord {
-        verifier_address: verifier_address.clone(),
-        farmer_address: farmer_address.clone(),
-        hectares_not_burned: 0, // ❌ Inválido
-        co2e_tons: 100,
-        metadata_hash,
+        hectares_not_burned: 0,
+        ..record(&env, &verifier_address, &farmer_address, 1, 100)
     };
     
     let result = client.try_mint_certificate(&1, &record);
@@ -628,16 +660,14 @@ fn test_mint_certificate_valid_data() {
     let client = CarbonCertifierClient::new(&env, &contract_id);
     
     let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
     let farmer_address = Address::generate(&env);
-    let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
+    register_parcel(&env, &client, 1, &farmer_address);
     
     // Datos válidos (ambos > 0)
     let record = VerificationRecord {
-        verifier_address: verifier_address.clone(),
-        farmer_address: farmer_address.clone(),
-        hectares_not_burned: 1, // ✅ Válido (mínimo valor válido)
-        co2e_tons: 1,          // ✅ Válido (mínimo valor válido)
-        metadata_hash,
+        hectares_not_burned: 1,
+        ..record(&env, &verifier_address, &farmer_address, 1, 1)
     };
     
     // Debe acuñar exitosamente
@@ -646,7 +676,7 @@ fn test_mint_certificate_valid_data() {
     // Verificar que el certificado existe
     let retrieved = client.get_certificate_data(&1);
     assert_eq!(retrieved.hectares_not_burned, 1);
-    assert_eq!(retrieved.co2e_tons, 1);
+    assert_eq!(retrieved.co2e_kg, 1);
 }
 
 // ============================================================================
@@ -662,17 +692,15 @@ fn test_pagination_first_page() {
     let client = CarbonCertifierClient::new(&env, &contract_id);
     
     let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
     let farmer_address = Address::generate(&env);
-    let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
     
     // Acuñar 10 certificados para el agricultor
     for i in 1..=10 {
+        register_parcel(&env, &client, i, &farmer_address);
         let record = VerificationRecord {
-            verifier_address: verifier_address.clone(),
-            farmer_address: farmer_address.clone(),
             hectares_not_burned: i * 10,
-            co2e_tons: (i * 100) as u128,
-            metadata_hash: metadata_hash.clone(),
+            ..record(&env, &verifier_address, &farmer_address, i, (i * 100) as u128)
         };
         client.mint_certificate(&i, &record);
     }
@@ -698,17 +726,15 @@ fn test_pagination_second_page() {
     let client = CarbonCertifierClient::new(&env, &contract_id);
     
     let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
     let farmer_address = Address::generate(&env);
-    let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
     
     // Acuñar 10 certificados para el agricultor
     for i in 1..=10 {
+        register_parcel(&env, &client, i, &farmer_address);
         let record = VerificationRecord {
-            verifier_address: verifier_address.clone(),
-            farmer_address: farmer_address.clone(),
             hectares_not_burned: i * 10,
-            co2e_tons: (i * 100) as u128,
-            metadata_hash: metadata_hash.clone(),
+            ..record(&env, &verifier_address, &farmer_address, i, (i * 100) as u128)
         };
         client.mint_certificate(&i, &record);
     }
@@ -734,27 +760,22 @@ fn test_pagination_verifier() {
     let client = CarbonCertifierClient::new(&env, &contract_id);
     
     let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
     let farmer1 = Address::generate(&env);
     let farmer2 = Address::generate(&env);
-    let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
     
     // Acuñar 8 certificados (4 de cada agricultor)
     for i in 1..=4 {
+        register_parcel(&env, &client, i, &farmer1);
+        register_parcel(&env, &client, i + 4, &farmer2);
         let record1 = VerificationRecord {
-            verifier_address: verifier_address.clone(),
-            farmer_address: farmer1.clone(),
             hectares_not_burned: i * 10,
-            co2e_tons: (i * 100) as u128,
-            metadata_hash: metadata_hash.clone(),
+            ..record(&env, &verifier_address, &farmer1, i, (i * 100) as u128)
         };
         client.mint_certificate(&i, &record1);
-        
         let record2 = VerificationRecord {
-            verifier_address: verifier_address.clone(),
-            farmer_address: farmer2.clone(),
             hectares_not_burned: (i + 4) * 10,
-            co2e_tons: ((i + 4) * 100) as u128,
-            metadata_hash: metadata_hash.clone(),
+            ..record(&env, &verifier_address, &farmer2, i + 4, ((i + 4) * 100) as u128)
         };
         client.mint_certificate(&(i + 4), &record2);
     }
@@ -782,17 +803,15 @@ fn test_pagination_edge_cases() {
     let client = CarbonCertifierClient::new(&env, &contract_id);
     
     let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
     let farmer_address = Address::generate(&env);
-    let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
     
     // Acuñar 3 certificados
     for i in 1..=3 {
+        register_parcel(&env, &client, i, &farmer_address);
         let record = VerificationRecord {
-            verifier_address: verifier_address.clone(),
-            farmer_address: farmer_address.clone(),
             hectares_not_burned: i * 10,
-            co2e_tons: (i * 100) as u128,
-            metadata_hash: metadata_hash.clone(),
+            ..record(&env, &verifier_address, &farmer_address, i, (i * 100) as u128)
         };
         client.mint_certificate(&i, &record);
     }
@@ -826,16 +845,11 @@ fn test_get_certificate_owner_initial() {
     let client = CarbonCertifierClient::new(&env, &contract_id);
     
     let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
     let farmer_address = Address::generate(&env);
-    let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
+    register_parcel(&env, &client, 1, &farmer_address);
     
-    let record = VerificationRecord {
-        verifier_address: verifier_address.clone(),
-        farmer_address: farmer_address.clone(),
-        hectares_not_burned: 10,
-        co2e_tons: 100,
-        metadata_hash,
-    };
+    let record = record(&env, &verifier_address, &farmer_address, 1, 100);
     
     // Acuñar certificado
     client.mint_certificate(&1, &record);
@@ -866,17 +880,12 @@ fn test_transfer_certificate_success() {
     let client = CarbonCertifierClient::new(&env, &contract_id);
     
     let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
     let farmer_address = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_address);
     let new_owner_address = Address::generate(&env);
-    let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
     
-    let record = VerificationRecord {
-        verifier_address: verifier_address.clone(),
-        farmer_address: farmer_address.clone(),
-        hectares_not_burned: 10,
-        co2e_tons: 100,
-        metadata_hash,
-    };
+    let record = record(&env, &verifier_address, &farmer_address, 1, 100);
     
     // Acuñar certificado
     client.mint_certificate(&1, &record);
@@ -900,17 +909,12 @@ fn test_transfer_certificate_unauthorized() {
     let client = CarbonCertifierClient::new(&env, &contract_id);
     
     let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
     let farmer_address = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_address);
     let thief_address = Address::generate(&env);
-    let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
     
-    let record = VerificationRecord {
-        verifier_address: verifier_address.clone(),
-        farmer_address: farmer_address.clone(),
-        hectares_not_burned: 10,
-        co2e_tons: 100,
-        metadata_hash,
-    };
+    let record = record(&env, &verifier_address, &farmer_address, 1, 100);
     
     // Acuñar certificado
     client.mint_certificate(&1, &record);
@@ -937,18 +941,13 @@ fn test_transfer_certificate_not_owner() {
     let client = CarbonCertifierClient::new(&env, &contract_id);
     
     let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
     let farmer_address = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_address);
     let fake_owner = Address::generate(&env);
     let new_owner = Address::generate(&env);
-    let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
     
-    let record = VerificationRecord {
-        verifier_address: verifier_address.clone(),
-        farmer_address: farmer_address.clone(),
-        hectares_not_burned: 10,
-        co2e_tons: 100,
-        metadata_hash,
-    };
+    let record = record(&env, &verifier_address, &farmer_address, 1, 100);
     
     // Acuñar certificado (propietario es farmer_address)
     client.mint_certificate(&1, &record);
@@ -969,18 +968,13 @@ fn test_transfer_certificate_chain() {
     let client = CarbonCertifierClient::new(&env, &contract_id);
     
     let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
     let address_a = Address::generate(&env);
+    register_parcel(&env, &client, 1, &address_a);
     let address_b = Address::generate(&env);
     let address_c = Address::generate(&env);
-    let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
     
-    let record = VerificationRecord {
-        verifier_address: verifier_address.clone(),
-        farmer_address: address_a.clone(),
-        hectares_not_burned: 10,
-        co2e_tons: 100,
-        metadata_hash,
-    };
+    let record = record(&env, &verifier_address, &address_a, 1, 100);
     
     // Acuñar certificado
     client.mint_certificate(&1, &record);
@@ -1036,10 +1030,95 @@ fn test_set_token_contract_id_unauthorized() {
 }
 
 #[test]
-#[ignore] // Ignorar esta prueba por ahora - requiere CarbonToken como dependencia
 fn test_mint_certificate_cross_contract_mint() {
-    // Esta prueba requiere CarbonToken como dependencia del módulo de pruebas
-    // Se puede implementar en un módulo de integración separado
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (_, token_id) = register_verifier(&env, &client, &verifier_address);
+    let token_client = CarbonTokenClient::new(&env, &token_id);
+    let farmer_address = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_address);
+    register_parcel(&env, &client, 2, &farmer_address);
+    
+    let record = record(&env, &verifier_address, &farmer_address, 1, 100);
+    
+    // Acuñar el certificado: el agricultor recibe 1 CXO por tonelada (0.001 CXO por kg)
+    client.mint_certificate(&1, &record);
+    assert_eq!(token_client.balance(&farmer_address), 100 * CXO_UNITS_PER_KG as i128);
+    
+    let record2 = VerificationRecord {
+        co2e_kg: 50,
+        parcel_id: 2,
+        ..record
+    };
+    client.mint_certificate(&2, &record2);
+    assert_eq!(token_client.balance(&farmer_address), 150 * CXO_UNITS_PER_KG as i128);
+}
+
+#[test]
+fn test_mint_certificate_token_mint_failed_is_atomic() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (admin_address, _) = register_verifier(&env, &client, &verifier_address);
+    let farmer_address = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_address);
+    
+    // Token en el que el certificador NO es minter: la acuñación cross-contract falla
+    let foreign_token_id = env.register(CarbonToken, ());
+    let foreign_token = CarbonTokenClient::new(&env, &foreign_token_id);
+    foreign_token.initialize(&admin_address);
+    client.set_token_contract_id(&admin_address, &foreign_token_id);
+    
+    let record = record(&env, &verifier_address, &farmer_address, 1, 100);
+    
+    let result = client.try_mint_certificate(&1, &record);
+    assert_eq!(result, Err(Ok(ContractError::TokenMintFailed)));
+    
+    // Ninguna escritura debe persistir
+    assert!(client.try_get_certificate_data(&1).is_err());
+    assert!(client.try_get_certificate_owner(&1).is_err());
+    assert_eq!(client.get_total_certificates(), 0);
+    assert_eq!(client.get_total_co2e(), 0);
+    assert_eq!(client.list_certificates_by_farmer(&farmer_address, &0, &10, &SortBy::CertificateId, &false).1, 0);
+    assert_eq!(client.list_certificates_by_verifier(&verifier_address, &0, &10).1, 0);
+    assert_eq!(foreign_token.balance(&farmer_address), 0);
+}
+
+#[test]
+fn test_mint_certificate_without_token_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let admin_address = Address::generate(&env);
+    let verifier_address = Address::generate(&env);
+    client.initialize(&admin_address);
+    client.add_verifier(
+        &admin_address,
+        &verifier_address,
+        &String::from_str(&env, "Ingenio Emiliano Zapata"),
+        &BytesN::from_array(&env, &[7u8; 32]),
+    );
+    client.set_emission_factor(&admin_address, &0, &symbol_short!("morelos"), &2024, &TEST_KG_CO2E_PER_HECTARE);
+    
+    let record = record(&env, &verifier_address, &Address::generate(&env), 1, 100);
+    register_parcel(&env, &client, 1, &record.farmer_address);
+    
+    // Sin contrato de token configurado no se puede emitir un certificado sin CXO
+    let result = client.try_mint_certificate(&1, &record);
+    assert_eq!(result, Err(Ok(ContractError::TokenMintFailed)));
+    assert_eq!(client.get_total_certificates(), 0);
 }
 
 // ============================================================================
@@ -1053,28 +1132,24 @@ fn test_burn_certificate_success() {
     
     let contract_id = env.register_contract(None, CarbonCertifier);
     let client = CarbonCertifierClient::new(&env, &contract_id);
+    let (name, reason) = claim(&env);
     
     let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
     let farmer_address = Address::generate(&env);
-    let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
+    register_parcel(&env, &client, 1, &farmer_address);
     
-    let record = VerificationRecord {
-        verifier_address: verifier_address.clone(),
-        farmer_address: farmer_address.clone(),
-        hectares_not_burned: 10,
-        co2e_tons: 100,
-        metadata_hash,
-    };
+    let record = record(&env, &verifier_address, &farmer_address, 1, 100);
     
     // Acuñar certificado
     client.mint_certificate(&1, &record);
     
     // Verificar que existe antes de quemar
     let cert_data = client.get_certificate_data(&1);
-    assert_eq!(cert_data.co2e_tons, 100);
+    assert_eq!(cert_data.co2e_kg, 100);
     
     // Quemar el certificado
-    client.burn_certificate(&1);
+    client.burn_certificate(&1, &farmer_address, &name, &reason);
     
     // Verificar que ya no existe después de quemar
     let result = client.try_get_certificate_data(&1);
@@ -1094,17 +1169,12 @@ fn test_burn_certificate_not_owner() {
     let client = CarbonCertifierClient::new(&env, &contract_id);
     
     let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
     let farmer_address = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_address);
     let attacker = Address::generate(&env);
-    let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
     
-    let record = VerificationRecord {
-        verifier_address: verifier_address.clone(),
-        farmer_address: farmer_address.clone(),
-        hectares_not_burned: 10,
-        co2e_tons: 100,
-        metadata_hash,
-    };
+    let record = record(&env, &verifier_address, &farmer_address, 1, 100);
     
     // Acuñar certificado (propietario es farmer_address)
     client.mint_certificate(&1, &record);
@@ -1126,9 +1196,10 @@ fn test_burn_certificate_not_exists() {
     
     let contract_id = env.register_contract(None, CarbonCertifier);
     let client = CarbonCertifierClient::new(&env, &contract_id);
+    let (name, reason) = claim(&env);
     
     // Intentar quemar un certificado que no existe
-    let result = client.try_burn_certificate(&999);
+    let result = client.try_burn_certificate(&999, &Address::generate(&env), &name, &reason);
     
     // Debe fallar con NotFound
     assert!(result.is_err());
@@ -1141,26 +1212,19 @@ fn test_burn_certificate_removes_from_farmer_list() {
     
     let contract_id = env.register_contract(None, CarbonCertifier);
     let client = CarbonCertifierClient::new(&env, &contract_id);
+    let (name, reason) = claim(&env);
     
     let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
     let farmer_address = Address::generate(&env);
-    let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
+    register_parcel(&env, &client, 1, &farmer_address);
+    register_parcel(&env, &client, 2, &farmer_address);
     
     // Acuñar dos certificados
-    let record1 = VerificationRecord {
-        verifier_address: verifier_address.clone(),
-        farmer_address: farmer_address.clone(),
-        hectares_not_burned: 10,
-        co2e_tons: 100,
-        metadata_hash: metadata_hash.clone(),
-    };
-    
+    let record1 = record(&env, &verifier_address, &farmer_address, 1, 100);
     let record2 = VerificationRecord {
-        verifier_address: verifier_address.clone(),
-        farmer_address: farmer_address.clone(),
         hectares_not_burned: 20,
-        co2e_tons: 200,
-        metadata_hash,
+        ..record(&env, &verifier_address, &farmer_address, 2, 200)
     };
     
     client.mint_certificate(&1, &record1);
@@ -1172,7 +1236,7 @@ fn test_burn_certificate_removes_from_farmer_list() {
     assert_eq!(farmer_certs.1, 2);
     
     // Quemar el certificado 1
-    client.burn_certificate(&1);
+    client.burn_certificate(&1, &farmer_address, &name, &reason);
     
     // Verificar que el farmer ahora tiene solo 1 certificado (ID 2)
     let farmer_certs_after = client.list_certificates_by_farmer(&farmer_address, &0, &10, &SortBy::CertificateId, &false);
@@ -1188,28 +1252,22 @@ fn test_burn_certificate_removes_from_verifier_list() {
     
     let contract_id = env.register_contract(None, CarbonCertifier);
     let client = CarbonCertifierClient::new(&env, &contract_id);
+    let (name, reason) = claim(&env);
     
     let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
     let farmer1_address = Address::generate(&env);
     let farmer2_address = Address::generate(&env);
-    let metadata_hash1 = BytesN::from_array(&env, &[0u8; 32]);
+    register_parcel(&env, &client, 1, &farmer1_address);
+    register_parcel(&env, &client, 2, &farmer2_address);
     let metadata_hash2 = BytesN::from_array(&env, &[1u8; 32]);
     
     // Acuñar dos certificados para el mismo verificador
-    let record1 = VerificationRecord {
-        verifier_address: verifier_address.clone(),
-        farmer_address: farmer1_address.clone(),
-        hectares_not_burned: 10,
-        co2e_tons: 100,
-        metadata_hash: metadata_hash1,
-    };
-    
+    let record1 = record(&env, &verifier_address, &farmer1_address, 1, 100);
     let record2 = VerificationRecord {
-        verifier_address: verifier_address.clone(),
-        farmer_address: farmer2_address.clone(),
         hectares_not_burned: 20,
-        co2e_tons: 200,
-        metadata_hash: metadata_hash2,
+        metadata_hash: metadata_hash2.clone(),
+        ..record(&env, &verifier_address, &farmer2_address, 2, 200)
     };
     
     client.mint_certificate(&1, &record1);
@@ -1221,7 +1279,7 @@ fn test_burn_certificate_removes_from_verifier_list() {
     assert_eq!(verifier_certs.1, 2);
     
     // Quemar el certificado 1
-    client.burn_certificate(&1);
+    client.burn_certificate(&1, &farmer1_address, &name, &reason);
     
     // Verificar que el verificador ahora tiene solo 1 certificado (ID 2)
     let verifier_certs_after = client.list_certificates_by_verifier(&verifier_address, &0, &10);
@@ -1237,18 +1295,14 @@ fn test_burn_certificate_updates_counters() {
     
     let contract_id = env.register_contract(None, CarbonCertifier);
     let client = CarbonCertifierClient::new(&env, &contract_id);
+    let (name, reason) = claim(&env);
     
     let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
     let farmer_address = Address::generate(&env);
-    let metadata_hash = BytesN::from_array(&env, &[0u8; 32]);
+    register_parcel(&env, &client, 1, &farmer_address);
     
-    let record = VerificationRecord {
-        verifier_address: verifier_address.clone(),
-        farmer_address: farmer_address.clone(),
-        hectares_not_burned: 10,
-        co2e_tons: 100,
-        metadata_hash,
-    };
+    let record = record(&env, &verifier_address, &farmer_address, 1, 100);
     
     // Acuñar certificado
     client.mint_certificate(&1, &record);
@@ -1258,7 +1312,7 @@ fn test_burn_certificate_updates_counters() {
     assert_eq!(client.get_total_co2e(), 100);
     
     // Quemar el certificado
-    client.burn_certificate(&1);
+    client.burn_certificate(&1, &farmer_address, &name, &reason);
     
     // Verificar que los contadores se redujeron
     assert_eq!(client.get_total_certificates(), 0);
@@ -1272,27 +1326,21 @@ fn test_burn_certificate_multiple_updates_counters() {
     
     let contract_id = env.register_contract(None, CarbonCertifier);
     let client = CarbonCertifierClient::new(&env, &contract_id);
+    let (name, reason) = claim(&env);
     
     let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
     let farmer_address = Address::generate(&env);
-    let metadata_hash1 = BytesN::from_array(&env, &[0u8; 32]);
+    register_parcel(&env, &client, 1, &farmer_address);
+    register_parcel(&env, &client, 2, &farmer_address);
     let metadata_hash2 = BytesN::from_array(&env, &[1u8; 32]);
     
     // Acuñar dos certificados
-    let record1 = VerificationRecord {
-        verifier_address: verifier_address.clone(),
-        farmer_address: farmer_address.clone(),
-        hectares_not_burned: 10,
-        co2e_tons: 100,
-        metadata_hash: metadata_hash1,
-    };
-    
+    let record1 = record(&env, &verifier_address, &farmer_address, 1, 100);
     let record2 = VerificationRecord {
-        verifier_address: verifier_address.clone(),
-        farmer_address: farmer_address.clone(),
         hectares_not_burned: 20,
-        co2e_tons: 200,
-        metadata_hash: metadata_hash2,
+        metadata_hash: metadata_hash2.clone(),
+        ..record(&env, &verifier_address, &farmer_address, 2, 200)
     };
     
     client.mint_certificate(&1, &record1);
@@ -1303,17 +1351,2561 @@ fn test_burn_certificate_multiple_updates_counters() {
     assert_eq!(client.get_total_co2e(), 300);
     
     // Quemar el primer certificado
-    client.burn_certificate(&1);
+    client.burn_certificate(&1, &farmer_address, &name, &reason);
     
     // Verificar que se actualizaron correctamente
     assert_eq!(client.get_total_certificates(), 1);
     assert_eq!(client.get_total_co2e(), 200);
     
     // Quemar el segundo certificado
-    client.burn_certificate(&2);
+    client.burn_certificate(&2, &farmer_address, &name, &reason);
     
     // Verificar que quedaron en cero
     assert_eq!(client.get_total_certificates(), 0);
     assert_eq!(client.get_total_co2e(), 0);
 }
 
+
+#[test]
+fn test_burn_certificate_burns_cxo() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    let (name, reason) = claim(&env);
+    
+    let verifier_address = Address::generate(&env);
+    let (_, token_id) = register_verifier(&env, &client, &verifier_address);
+    let token_client = CarbonTokenClient::new(&env, &token_id);
+    let farmer_address = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_address);
+    register_parcel(&env, &client, 2, &farmer_address);
+    
+    let record = record(&env, &verifier_address, &farmer_address, 1, 100);
+    client.mint_certificate(&1, &record);
+    client.mint_certificate(&2, &VerificationRecord { co2e_kg: 40, parcel_id: 2, ..record });
+    let units = CXO_UNITS_PER_KG as i128;
+    assert_eq!(token_client.balance(&farmer_address), 140 * units);
+    
+    // Retirar el certificado 1 quema los CXO de sus 100 kg
+    client.burn_certificate(&1, &farmer_address, &name, &reason);
+    assert_eq!(token_client.balance(&farmer_address), 40 * units);
+    
+    // La oferta restante coincide con el tonelaje no retirado
+    assert_eq!(client.get_total_co2e(), 40);
+    assert_eq!(token_client.total_supply(), 40 * units);
+    assert_eq!(token_client.total_minted(), 140 * units);
+    assert_eq!(token_client.total_burned(), 100 * units);
+    assert_eq!(client.get_total_retired_co2e(), 100);
+}
+
+#[test]
+fn test_burn_certificate_insufficient_cxo_is_atomic() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    let (name, reason) = claim(&env);
+    
+    let verifier_address = Address::generate(&env);
+    let (_, token_id) = register_verifier(&env, &client, &verifier_address);
+    let token_client = CarbonTokenClient::new(&env, &token_id);
+    let farmer_address = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_address);
+    let buyer_address = Address::generate(&env);
+    
+    let record = record(&env, &verifier_address, &farmer_address, 1, 100);
+    client.mint_certificate(&1, &record);
+    
+    // El agricultor vende parte de sus CXO: ya no puede retirar el certificado completo
+    token_client.transfer(&farmer_address, &buyer_address, &(30 * CXO_UNITS_PER_KG as i128));
+    
+    let result = client.try_burn_certificate(&1, &farmer_address, &name, &reason);
+    assert_eq!(result, Err(Ok(ContractError::TokenBurnFailed)));
+    
+    // El certificado, los contadores y los balances quedan intactos
+    assert_eq!(client.get_certificate_data(&1), record);
+    assert_eq!(client.get_certificate_owner(&1), farmer_address);
+    assert_eq!(client.get_total_certificates(), 1);
+    assert_eq!(client.get_total_co2e(), 100);
+    assert_eq!(token_client.balance(&farmer_address), 70 * CXO_UNITS_PER_KG as i128);
+}
+
+#[test]
+fn test_burn_transferred_certificate_requires_new_owner_cxo() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    let (name, reason) = claim(&env);
+    
+    let verifier_address = Address::generate(&env);
+    let (_, token_id) = register_verifier(&env, &client, &verifier_address);
+    let token_client = CarbonTokenClient::new(&env, &token_id);
+    let farmer_address = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_address);
+    let buyer_address = Address::generate(&env);
+    
+    let record = record(&env, &verifier_address, &farmer_address, 1, 100);
+    client.mint_certificate(&1, &record);
+    
+    // Solo se transfiere el NFT: el comprador no tiene CXO para retirarlo
+    client.transfer_certificate(&1, &farmer_address, &buyer_address);
+    let result = client.try_burn_certificate(&1, &buyer_address, &name, &reason);
+    assert_eq!(result, Err(Ok(ContractError::TokenBurnFailed)));
+    
+    // Al recibir también los CXO, el comprador puede retirar el certificado
+    token_client.transfer(&farmer_address, &buyer_address, &(100 * CXO_UNITS_PER_KG as i128));
+    client.burn_certificate(&1, &buyer_address, &name, &reason);
+    assert_eq!(token_client.balance(&buyer_address), 0);
+    assert_eq!(client.get_total_certificates(), 0);
+}
+
+// ============================================================================
+// Tests para el registro de verificadores
+// ============================================================================
+
+#[test]
+fn test_add_verifier_success() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
+    
+    // Verificar los metadatos registrados
+    let info = client.get_verifier(&verifier_address);
+    assert_eq!(info.organization_name, String::from_str(&env, "Ingenio Emiliano Zapata"));
+    assert_eq!(info.accreditation_hash, BytesN::from_array(&env, &[7u8; 32]));
+    assert_eq!(info.status, VerifierStatus::Active);
+    assert!(client.is_active_verifier(&verifier_address));
+}
+
+#[test]
+fn test_add_verifier_not_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let admin_address = Address::generate(&env);
+    let non_admin_address = Address::generate(&env);
+    let verifier_address = Address::generate(&env);
+    client.initialize(&admin_address);
+    
+    // Un no-admin no puede registrar verificadores
+    let result = client.try_add_verifier(
+        &non_admin_address,
+        &verifier_address,
+        &String::from_str(&env, "Verificador Falso"),
+        &BytesN::from_array(&env, &[0u8; 32]),
+    );
+    assert_eq!(result, Err(unauthorized()));
+    assert!(!client.is_active_verifier(&verifier_address));
+}
+
+#[test]
+fn test_add_verifier_already_exists() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (admin_address, _) = register_verifier(&env, &client, &verifier_address);
+    
+    // Registrar dos veces el mismo verificador debe fallar
+    let result = client.try_add_verifier(
+        &admin_address,
+        &verifier_address,
+        &String::from_str(&env, "Ingenio Emiliano Zapata"),
+        &BytesN::from_array(&env, &[7u8; 32]),
+    );
+    assert_eq!(result, Err(Ok(ContractError::AlreadyExists)));
+}
+
+#[test]
+fn test_mint_certificate_unregistered_verifier() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let self_declared_verifier = Address::generate(&env);
+    let farmer_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
+    
+    // Un verificador auto-declarado no puede acuñar aunque firme la transacción
+    let record = record(&env, &self_declared_verifier, &farmer_address, 1, 100);
+    
+    let result = client.try_mint_certificate(&1, &record);
+    assert_eq!(result, Err(Ok(ContractError::VerifierNotAuthorized)));
+    assert_eq!(client.get_total_certificates(), 0);
+}
+
+#[test]
+fn test_suspend_and_reactivate_verifier() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let farmer_address = Address::generate(&env);
+    let (admin_address, _) = register_verifier(&env, &client, &verifier_address);
+    register_parcel(&env, &client, 1, &farmer_address);
+    
+    let record = record(&env, &verifier_address, &farmer_address, 1, 100);
+    
+    // Suspender: el verificador ya no puede acuñar
+    client.suspend_verifier(&admin_address, &verifier_address);
+    assert_eq!(client.get_verifier(&verifier_address).status, VerifierStatus::Suspended);
+    assert!(!client.is_active_verifier(&verifier_address));
+    let result = client.try_mint_certificate(&1, &record);
+    assert_eq!(result, Err(Ok(ContractError::VerifierNotAuthorized)));
+    
+    // Reactivar: vuelve a poder acuñar
+    client.reactivate_verifier(&admin_address, &verifier_address);
+    assert!(client.is_active_verifier(&verifier_address));
+    client.mint_certificate(&1, &record);
+    assert_eq!(client.get_total_certificates(), 1);
+}
+
+#[test]
+fn test_remove_verifier() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let farmer_address = Address::generate(&env);
+    let (admin_address, _) = register_verifier(&env, &client, &verifier_address);
+    register_parcel(&env, &client, 1, &farmer_address);
+    
+    let record = record(&env, &verifier_address, &farmer_address, 1, 100);
+    client.mint_certificate(&1, &record);
+    
+    // Eliminar el verificador
+    client.remove_verifier(&admin_address, &verifier_address);
+    assert!(client.try_get_verifier(&verifier_address).is_err());
+    
+    // Ya no puede acuñar, pero sus certificados existentes se conservan
+    let result = client.try_mint_certificate(&2, &record);
+    assert_eq!(result, Err(Ok(ContractError::VerifierNotAuthorized)));
+    assert_eq!(client.get_certificate_data(&1), record);
+    
+    // Eliminar un verificador inexistente falla
+    let result = client.try_remove_verifier(&admin_address, &verifier_address);
+    assert_eq!(result, Err(Ok(ContractError::NotFound)));
+}
+
+// ============================================================================
+// TONELAJE FRACCIONARIO
+// ============================================================================
+
+#[test]
+fn test_mint_fractional_tonnage() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (_, token_id) = register_verifier(&env, &client, &verifier_address);
+    let token_client = CarbonTokenClient::new(&env, &token_id);
+    let farmer_address = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_address);
+    register_parcel(&env, &client, 2, &farmer_address);
+    
+    // 12.4 toneladas de CO2e = 12,400 kg
+    let record = VerificationRecord {
+        hectares_not_burned: 3,
+        ..record(&env, &verifier_address, &farmer_address, 1, 12_400)
+    };
+    client.mint_certificate(&1, &record);
+    
+    // ✅ El agricultor recibe exactamente 12.4 CXO (7 decimales)
+    assert_eq!(token_client.decimals(), 7);
+    assert_eq!(token_client.balance(&farmer_address), 124_000_000);
+    
+    let amount = client.get_certificate_co2e(&1);
+    assert_eq!(amount, Co2eAmount { kg: 12_400, tons: 12, remainder_kg: 400, cxo_units: 124_000_000 });
+    
+    // 0.85 toneladas adicionales
+    client.mint_certificate(&2, &VerificationRecord { co2e_kg: 850, parcel_id: 2, ..record });
+    let total = client.get_total_co2e_amount();
+    assert_eq!(total, Co2eAmount { kg: 13_250, tons: 13, remainder_kg: 250, cxo_units: 132_500_000 });
+    assert_eq!(token_client.total_supply(), 132_500_000);
+}
+
+#[test]
+fn test_get_certificate_co2e_not_found() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let result = client.try_get_certificate_co2e(&99);
+    assert_eq!(result, Err(Ok(ContractError::NotFound)));
+}
+
+#[test]
+fn test_mint_certificate_cxo_overflow_is_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (admin, _) = register_verifier(&env, &client, &verifier_address);
+    client.set_emission_factor(&admin, &0, &symbol_short!("morelos"), &2024, &(u128::MAX / 10));
+    
+    // Un tonelaje cuya conversión a unidades CXO desborda i128 no se acuña
+    let record = record(&env, &verifier_address, &Address::generate(&env), 1, u128::MAX / CXO_UNITS_PER_KG);
+    register_parcel(&env, &client, 1, &record.farmer_address);
+    let result = client.try_mint_certificate(&1, &record);
+    assert_eq!(result, Err(Ok(ContractError::TokenMintFailed)));
+    assert_eq!(client.get_total_co2e(), 0);
+}
+
+// ============================================================================
+// RETIRO PARCIAL
+// ============================================================================
+
+#[test]
+fn test_retire_partial_keeps_certificate_alive() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (_, token_id) = register_verifier(&env, &client, &verifier_address);
+    let token_client = CarbonTokenClient::new(&env, &token_id);
+    let farmer_address = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_address);
+    let beneficiary = Address::generate(&env);
+    let name = String::from_str(&env, "Grupo Modelo");
+    let reason = String::from_str(&env, "Compensación 2024");
+    
+    // Certificado de 500 t, se compensan 120 t
+    let record = VerificationRecord {
+        hectares_not_burned: 50,
+        ..record(&env, &verifier_address, &farmer_address, 1, 500_000)
+    };
+    client.mint_certificate(&1, &record);
+    client.retire_partial(&1, &120_000, &beneficiary, &name, &reason);
+    
+    // ✅ El certificado sigue vivo con 380 t vigentes
+    assert_eq!(client.get_certificate_data(&1).co2e_kg, 380_000);
+    assert_eq!(client.get_certificate_owner(&1), farmer_address);
+    assert_eq!(client.get_certificate_retired(&1), 120_000);
+    assert_eq!(client.get_total_certificates(), 1);
+    assert_eq!(client.get_total_co2e(), 380_000);
+    assert_eq!(client.get_total_retired_co2e(), 120_000);
+    assert_eq!(token_client.balance(&farmer_address), 380_000 * CXO_UNITS_PER_KG as i128);
+    
+    // ✅ Retirar el resto elimina el certificado
+    client.retire_partial(&1, &380_000, &beneficiary, &name, &reason);
+    assert_eq!(client.try_get_certificate_data(&1), Err(Ok(ContractError::NotFound)));
+    assert_eq!(client.get_certificate_retired(&1), 0);
+    assert_eq!(client.get_total_certificates(), 0);
+    assert_eq!(client.get_total_co2e(), 0);
+    assert_eq!(client.get_total_retired_co2e(), 500_000);
+    assert_eq!(client.list_certificates_by_farmer(&farmer_address, &0, &10, &SortBy::CertificateId, &false).0.len(), 0);
+    assert_eq!(token_client.balance(&farmer_address), 0);
+}
+
+#[test]
+fn test_retire_partial_invalid_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
+    let beneficiary = Address::generate(&env);
+    let name = String::from_str(&env, "Grupo Modelo");
+    let reason = String::from_str(&env, "Compensación 2024");
+    
+    let record = record(&env, &verifier_address, &Address::generate(&env), 1, 1_000);
+    register_parcel(&env, &client, 1, &record.farmer_address);
+    client.mint_certificate(&1, &record);
+    
+    assert_eq!(client.try_retire_partial(&1, &0, &beneficiary, &name, &reason), Err(Ok(ContractError::InvalidInput)));
+    assert_eq!(client.try_retire_partial(&1, &1_001, &beneficiary, &name, &reason), Err(Ok(ContractError::InvalidInput)));
+    assert_eq!(client.try_retire_partial(&2, &1, &beneficiary, &name, &reason), Err(Ok(ContractError::NotFound)));
+    assert_eq!(client.get_total_retired_co2e(), 0);
+}
+
+#[test]
+fn test_retire_partial_insufficient_cxo_is_atomic() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (_, token_id) = register_verifier(&env, &client, &verifier_address);
+    let token_client = CarbonTokenClient::new(&env, &token_id);
+    let farmer_address = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_address);
+    
+    let record = record(&env, &verifier_address, &farmer_address, 1, 1_000);
+    client.mint_certificate(&1, &record);
+    token_client.transfer(&farmer_address, Address::generate(&env), &(900 * CXO_UNITS_PER_KG as i128));
+    
+    let name = String::from_str(&env, "Grupo Modelo");
+    let reason = String::from_str(&env, "Compensación 2024");
+    let result = client.try_retire_partial(&1, &200, &Address::generate(&env), &name, &reason);
+    assert_eq!(result, Err(Ok(ContractError::TokenBurnFailed)));
+    assert_eq!(client.get_certificate_data(&1).co2e_kg, 1_000);
+    assert_eq!(client.get_certificate_retired(&1), 0);
+    assert_eq!(client.get_total_retired_co2e(), 0);
+}
+
+// ============================================================================
+// DIVISIÓN Y FUSIÓN DE CERTIFICADOS
+// ============================================================================
+
+#[test]
+fn test_split_certificate() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (_, token_id) = register_verifier(&env, &client, &verifier_address);
+    let token_client = CarbonTokenClient::new(&env, &token_id);
+    let farmer_address = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_address);
+    let record = VerificationRecord {
+        metadata_hash: BytesN::from_array(&env, &[3u8; 32]),
+        ..record(&env, &verifier_address, &farmer_address, 1, 1_000)
+    };
+    client.mint_certificate(&5, &VerificationRecord { co2e_kg: 1_200, ..record.clone() });
+    client.retire_partial(&5, &200, &Address::generate(&env), &String::from_str(&env, "Grupo Modelo"), &String::from_str(&env, "Compensación 2024"));
+    
+    let child_ids = client.split_certificate(&5, &vec![&env, 600u128, 300, 100]);
+    
+    // ✅ Los hijos reciben IDs nuevos posteriores al mayor ID acuñado
+    assert_eq!(child_ids, vec![&env, 6u32, 7, 8]);
+    assert_eq!(client.try_get_certificate_data(&5), Err(Ok(ContractError::NotFound)));
+    
+    // ✅ El padre queda dividido y su ID no puede volver a acuñarse
+    assert_eq!(client.get_certificate_status(&5), CertificateStatus::Split);
+    assert_eq!(client.try_mint_certificate(&5, &record), Err(Ok(ContractError::AlreadyExists)));
+    // ✅ Las 10 ha del padre se reparten en proporción al CO2e de cada hijo
+    for (child_id, (co2e_kg, hectares_not_burned)) in child_ids.iter().zip([(600u128, 6u32), (300, 3), (100, 1)]) {
+        let child = client.get_certificate_data(&child_id);
+        assert_eq!(child, VerificationRecord { co2e_kg, hectares_not_burned, ..record.clone() });
+        assert_eq!(client.get_certificate_owner(&child_id), farmer_address);
+        assert_eq!(client.get_certificate_parent(&child_id), Some(5));
+    }
+    
+    // ✅ El primer hijo hereda la (parcela, zafra) y el historial de retiro del padre
+    assert_eq!(client.get_parcel_certificate(&1, &2024), Some(6));
+    assert_eq!(client.get_certificate_retired(&6), 200);
+    assert_eq!(client.get_certificate_retired(&7), 0);
+    assert_eq!(client.get_certificate_retired(&5), 0);
+    
+    // ✅ Índices y contadores consistentes; CXO sin cambios
+    let (farmer_certs, total) = client.list_certificates_by_farmer(&farmer_address, &0, &10, &SortBy::CertificateId, &false);
+    assert_eq!(farmer_certs, child_ids);
+    assert_eq!(total, 3);
+    assert_eq!(client.list_certificates_by_verifier(&verifier_address, &0, &10).1, 3);
+    assert_eq!(client.get_total_certificates(), 3);
+    assert_eq!(client.get_total_co2e(), 1_000);
+    assert_eq!(token_client.balance(&farmer_address), 1_000 * CXO_UNITS_PER_KG as i128);
+    
+    // ✅ Sin reparto exacto, el último hijo recibe el resto y las hectáreas suman las del padre
+    let grandchild_ids = client.split_certificate(&7, &vec![&env, 150u128, 150]);
+    assert_eq!(client.get_certificate_data(&grandchild_ids.get_unchecked(0)).hectares_not_burned, 1);
+    assert_eq!(client.get_certificate_data(&grandchild_ids.get_unchecked(1)).hectares_not_burned, 2);
+}
+
+#[test]
+fn test_split_certificate_invalid_amounts() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
+    
+    let record = record(&env, &verifier_address, &Address::generate(&env), 1, 1_000);
+    register_parcel(&env, &client, 1, &record.farmer_address);
+    client.mint_certificate(&1, &record);
+    
+    // Una sola parte, suma distinta y parte en cero
+    assert_eq!(client.try_split_certificate(&1, &vec![&env, 1_000u128]), Err(Ok(ContractError::InvalidInput)));
+    assert_eq!(client.try_split_certificate(&1, &vec![&env, 600u128, 300]), Err(Ok(ContractError::InvalidInput)));
+    assert_eq!(client.try_split_certificate(&1, &vec![&env, 1_000u128, 0]), Err(Ok(ContractError::InvalidInput)));
+    assert_eq!(client.try_split_certificate(&2, &vec![&env, 500u128, 500]), Err(Ok(ContractError::NotFound)));
+    assert_eq!(client.get_certificate_data(&1), record);
+}
+
+#[test]
+fn test_merge_certificates() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
+    let farmer_address = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_address);
+    let record = VerificationRecord {
+        metadata_hash: BytesN::from_array(&env, &[3u8; 32]),
+        ..record(&env, &verifier_address, &farmer_address, 1, 1_000)
+    };
+    client.mint_certificate(&1, &record);
+    let child_ids = client.split_certificate(&1, &vec![&env, 500u128, 300, 200]);
+    let beneficiary = Address::generate(&env);
+    let name = String::from_str(&env, "Grupo Modelo");
+    let reason = String::from_str(&env, "Compensación 2024");
+    client.retire_partial(&child_ids.get_unchecked(0), &100, &beneficiary, &name, &reason);
+    client.retire_partial(&child_ids.get_unchecked(2), &50, &beneficiary, &name, &reason);
+    
+    // Fusionar dos de los tres hijos
+    let merged_id = client.merge_certificates(&vec![&env, child_ids.get_unchecked(0), child_ids.get_unchecked(2)]);
+    assert_eq!(client.get_certificate_data(&merged_id), VerificationRecord {
+        co2e_kg: 550,
+        hectares_not_burned: 7,
+        ..record.clone()
+    });
+    assert_eq!(client.get_certificate_owner(&merged_id), farmer_address);
+    assert_eq!(client.get_certificate_parent(&merged_id), Some(1));
+    
+    // ✅ El resultante hereda la (parcela, zafra) y suma el historial de retiro
+    assert_eq!(client.get_parcel_certificate(&1, &2024), Some(merged_id));
+    assert_eq!(client.get_certificate_retired(&merged_id), 150);
+    assert_eq!(client.get_certificate_retired(&child_ids.get_unchecked(0)), 0);
+    
+    // ✅ Los fusionados quedan cerrados y sus IDs no pueden volver a acuñarse
+    for id in [child_ids.get_unchecked(0), child_ids.get_unchecked(2)] {
+        assert_eq!(client.get_certificate_status(&id), CertificateStatus::Merged);
+        assert_eq!(client.try_mint_certificate(&id, &record), Err(Ok(ContractError::AlreadyExists)));
+    }
+    
+    let (farmer_certs, _) = client.list_certificates_by_farmer(&farmer_address, &0, &10, &SortBy::CertificateId, &false);
+    assert_eq!(farmer_certs, vec![&env, child_ids.get_unchecked(1), merged_id]);
+    assert_eq!(client.get_total_certificates(), 2);
+    assert_eq!(client.get_total_co2e(), 850);
+}
+
+#[test]
+fn test_merge_certificates_requires_same_provenance_and_owner() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (admin, _) = register_verifier(&env, &client, &verifier_address);
+    client.set_emission_factor(&admin, &0, &symbol_short!("morelos"), &2025, &TEST_KG_CO2E_PER_HECTARE);
+    let farmer_address = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_address);
+    register_parcel(&env, &client, 2, &farmer_address);
+    register_parcel(&env, &client, 3, &farmer_address);
+    let record = VerificationRecord {
+        metadata_hash: BytesN::from_array(&env, &[3u8; 32]),
+        ..record(&env, &verifier_address, &farmer_address, 1, 1_000)
+    };
+    client.mint_certificate(&1, &record);
+    // Mismo informe MRV y parcela, zafra siguiente
+    client.mint_certificate(&2, &VerificationRecord { season: 2025, ..record.clone() });
+    client.mint_certificate(&3, &VerificationRecord {
+        metadata_hash: BytesN::from_array(&env, &[4u8; 32]),
+        parcel_id: 2,
+        ..record.clone()
+    });
+    client.mint_certificate(&4, &VerificationRecord { parcel_id: 3, ..record.clone() });
+    client.transfer_certificate(&4, &farmer_address, &Address::generate(&env));
+    
+    assert_eq!(client.try_merge_certificates(&vec![&env, 1u32]), Err(Ok(ContractError::InvalidInput)));
+    assert_eq!(client.try_merge_certificates(&vec![&env, 1u32, 1]), Err(Ok(ContractError::InvalidInput)));
+    assert_eq!(client.try_merge_certificates(&vec![&env, 1u32, 3]), Err(Ok(ContractError::InvalidInput)));
+    assert_eq!(client.try_merge_certificates(&vec![&env, 1u32, 4]), Err(Ok(ContractError::NotOwner)));
+    assert_eq!(client.try_merge_certificates(&vec![&env, 1u32, 9]), Err(Ok(ContractError::NotFound)));
+    
+    // ✅ Misma procedencia y propietario: sin enlace a padre
+    // ❌ Misma parcela e informe MRV pero distinta zafra
+    assert_eq!(client.try_merge_certificates(&vec![&env, 1u32, 2]), Err(Ok(ContractError::InvalidInput)));
+    assert_eq!(client.get_certificate_status(&1), CertificateStatus::Active);
+    assert_eq!(client.get_certificate_status(&2), CertificateStatus::Active);
+    
+    // ✅ Misma procedencia y propietario: conserva el enlace al padre común y
+    // recupera las hectáreas repartidas al dividir
+    let child_ids = client.split_certificate(&2, &vec![&env, 600u128, 400]);
+    assert_eq!(child_ids, vec![&env, 5u32, 6]);
+    let merged_id = client.merge_certificates(&child_ids);
+    assert_eq!(merged_id, 7);
+    assert_eq!(client.get_certificate_data(&7), VerificationRecord { season: 2025, ..record });
+    assert_eq!(client.get_certificate_parent(&7), Some(2));
+    assert_eq!(client.get_total_certificates(), 4);
+}
+
+// ============================================================================
+// RECIBOS DE RETIRO
+// ============================================================================
+
+#[test]
+fn test_retire_partial_issues_receipt() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
+    let farmer_address = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_address);
+    let beneficiary = Address::generate(&env);
+    let record = VerificationRecord {
+        metadata_hash: BytesN::from_array(&env, &[9u8; 32]),
+        ..record(&env, &verifier_address, &farmer_address, 1, 1_000)
+    };
+    client.mint_certificate(&1, &record);
+    
+    let name = String::from_str(&env, "Grupo Modelo");
+    let reason = String::from_str(&env, "Compensación de emisiones 2024");
+    env.ledger().set_timestamp(1_700_000_000);
+    let receipt_id = client.retire_partial(&1, &400, &beneficiary, &name, &reason);
+    
+    // ✅ El recibo conserva los datos originales del certificado
+    let receipt = client.get_retirement_receipt(&receipt_id);
+    assert_eq!(receipt, RetirementReceipt {
+        receipt_id: 1,
+        certificate_id: 1,
+        retired_by: farmer_address.clone(),
+        beneficiary: beneficiary.clone(),
+        beneficiary_name: name.clone(),
+        reason: reason.clone(),
+        timestamp: 1_700_000_000,
+        verifier_address: verifier_address.clone(),
+        farmer_address: farmer_address.clone(),
+        hectares_not_burned: 10,
+        metadata_hash: record.metadata_hash.clone(),
+        co2e_kg: 400,
+        original_co2e_kg: 1_000,
+    });
+    
+    // ✅ El recibo sobrevive al retiro total del certificado
+    let second_id = client.retire_partial(&1, &600, &beneficiary, &name, &reason);
+    assert_eq!(client.try_get_certificate_data(&1), Err(Ok(ContractError::NotFound)));
+    assert_eq!(client.get_retirement_receipt(&second_id).co2e_kg, 600);
+    assert_eq!(client.get_retirement_receipt(&second_id).original_co2e_kg, 1_000);
+    assert_eq!(client.get_retirement_receipt(&receipt_id), receipt);
+    
+    let (receipts, total) = client.list_receipts_by_beneficiary(&beneficiary, &0, &10);
+    assert_eq!(receipts, vec![&env, 1u32, 2]);
+    assert_eq!(total, 2);
+    assert_eq!(client.get_total_receipts(), 2);
+}
+
+#[test]
+fn test_burn_certificate_issues_receipt_to_beneficiary() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    let (name, reason) = claim(&env);
+    
+    let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
+    let farmer_address = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_address);
+    let beneficiary = Address::generate(&env);
+    let record = VerificationRecord {
+        metadata_hash: BytesN::from_array(&env, &[9u8; 32]),
+        ..record(&env, &verifier_address, &farmer_address, 1, 1_000)
+    };
+    client.mint_certificate(&1, &record);
+    client.retire_partial(&1, &300, &farmer_address, &name, &reason);
+    let receipt_id = client.burn_certificate(&1, &beneficiary, &name, &reason);
+    
+    // ✅ La quema registra beneficiario, nombre y motivo, lo quemado y el tonelaje original
+    let receipt = client.get_retirement_receipt(&receipt_id);
+    assert_eq!(receipt.beneficiary, beneficiary);
+    assert_eq!(receipt.beneficiary_name, name);
+    assert_eq!(receipt.reason, reason);
+    assert_eq!(receipt.retired_by, farmer_address);
+    assert_eq!(receipt.co2e_kg, 700);
+    assert_eq!(receipt.original_co2e_kg, 1_000);
+    assert_eq!(client.list_receipts_by_beneficiary(&beneficiary, &0, &10).0, vec![&env, receipt_id]);
+}
+
+#[test]
+fn test_get_retirement_receipt_not_found() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    assert_eq!(client.try_get_retirement_receipt(&1), Err(Ok(ContractError::NotFound)));
+    assert_eq!(client.list_receipts_by_beneficiary(&Address::generate(&env), &0, &10).1, 0);
+}
+
+// ============================================================================
+// REVOCACIÓN DE CERTIFICADOS
+// ============================================================================
+
+#[test]
+fn test_revoke_certificate_by_verifier() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (_, token_id) = register_verifier(&env, &client, &verifier_address);
+    let token_client = CarbonTokenClient::new(&env, &token_id);
+    let farmer_address = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_address);
+    register_parcel(&env, &client, 2, &farmer_address);
+    
+    let record = record(&env, &verifier_address, &farmer_address, 1, 1_000);
+    client.mint_certificate(&1, &record);
+    client.mint_certificate(&2, &VerificationRecord { co2e_kg: 500, parcel_id: 2, ..record.clone() });
+    
+    let reason_hash = BytesN::from_array(&env, &[66u8; 32]);
+    client.revoke_certificate(&verifier_address, &1, &reason_hash);
+    
+    // ✅ El certificado se conserva pero queda revocado y fuera del total
+    assert!(client.is_certificate_revoked(&1));
+    assert!(!client.is_certificate_revoked(&2));
+    assert_eq!(client.get_certificate_data(&1), record);
+    assert_eq!(client.get_total_co2e(), 500);
+    
+    // ✅ Los CXO del certificado se recuperan del propietario
+    let units = CXO_UNITS_PER_KG as i128;
+    assert_eq!(token_client.balance(&farmer_address), 500 * units);
+    let revocation = client.get_revocation(&1);
+    assert_eq!(revocation.revoked_by, verifier_address);
+    assert_eq!(revocation.reason_hash, reason_hash);
+    assert_eq!(revocation.co2e_kg_revoked, 1_000);
+    assert_eq!(revocation.cxo_clawed_back, 1_000 * units);
+    assert_eq!(revocation.cxo_unrecovered, 0);
+    
+    // ✅ No puede revocarse dos veces
+    let result = client.try_revoke_certificate(&verifier_address, &1, &reason_hash);
+    assert_eq!(result, Err(Ok(ContractError::CertificateRevoked)));
+}
+
+#[test]
+fn test_revoke_certificate_blocks_transfer_and_retirement() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (admin, _) = register_verifier(&env, &client, &verifier_address);
+    client.grant_role(&admin, &admin, &Symbol::new(&env, REVOKER_ROLE));
+    let farmer_address = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_address);
+    register_parcel(&env, &client, 2, &farmer_address);
+    let buyer_address = Address::generate(&env);
+    
+    let record = record(&env, &verifier_address, &farmer_address, 1, 1_000);
+    client.mint_certificate(&1, &record);
+    client.mint_certificate(&2, &VerificationRecord { parcel_id: 2, ..record.clone() });
+    
+    // El admin también puede revocar
+    client.revoke_certificate(&admin, &1, &BytesN::from_array(&env, &[66u8; 32]));
+    
+    let name = String::from_str(&env, "Grupo Modelo");
+    let reason = String::from_str(&env, "Compensación 2024");
+    let revoked = ContractError::CertificateRevoked;
+    assert_eq!(client.try_transfer_certificate(&1, &farmer_address, &buyer_address), Err(Ok(revoked)));
+    assert_eq!(client.try_burn_certificate(&1, &farmer_address, &name, &reason), Err(Ok(revoked)));
+    assert_eq!(client.try_retire_partial(&1, &100, &buyer_address, &name, &reason), Err(Ok(revoked)));
+    assert_eq!(client.try_split_certificate(&1, &vec![&env, 500u128, 500]), Err(Ok(revoked)));
+    assert_eq!(client.try_merge_certificates(&vec![&env, 2u32, 1]), Err(Ok(revoked)));
+    assert_eq!(client.get_certificate_owner(&1), farmer_address);
+}
+
+#[test]
+fn test_revoke_certificate_unauthorized() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let other_verifier = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
+    
+    let record = record(&env, &verifier_address, &Address::generate(&env), 1, 1_000);
+    register_parcel(&env, &client, 1, &record.farmer_address);
+    client.mint_certificate(&1, &record);
+    
+    let reason_hash = BytesN::from_array(&env, &[66u8; 32]);
+    let result = client.try_revoke_certificate(&other_verifier, &1, &reason_hash);
+    assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+    let result = client.try_revoke_certificate(&verifier_address, &2, &reason_hash);
+    assert_eq!(result, Err(Ok(ContractError::NotFound)));
+    assert!(!client.is_certificate_revoked(&1));
+    assert_eq!(client.get_total_co2e(), 1_000);
+}
+
+#[test]
+fn test_revoke_certificate_by_former_verifier() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (admin, _) = register_verifier(&env, &client, &verifier_address);
+    client.grant_role(&admin, &admin, &Symbol::new(&env, REVOKER_ROLE));
+    
+    let record = record(&env, &verifier_address, &Address::generate(&env), 1, 1_000);
+    register_parcel(&env, &client, 1, &record.farmer_address);
+    client.mint_certificate(&1, &record);
+    let reason_hash = BytesN::from_array(&env, &[66u8; 32]);
+    
+    // ❌ Un emisor suspendido o eliminado del registro ya no puede revocar
+    client.suspend_verifier(&admin, &verifier_address);
+    let result = client.try_revoke_certificate(&verifier_address, &1, &reason_hash);
+    assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+    client.remove_verifier(&admin, &verifier_address);
+    let result = client.try_revoke_certificate(&verifier_address, &1, &reason_hash);
+    assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+    assert!(!client.is_certificate_revoked(&1));
+    
+    // ✅ El rol `revoker` sí puede
+    client.revoke_certificate(&admin, &1, &reason_hash);
+    assert!(client.is_certificate_revoked(&1));
+}
+
+#[test]
+fn test_revoke_certificate_records_unrecovered_cxo() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (_, token_id) = register_verifier(&env, &client, &verifier_address);
+    let token_client = CarbonTokenClient::new(&env, &token_id);
+    let farmer_address = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_address);
+    let buyer_address = Address::generate(&env);
+    
+    let record = record(&env, &verifier_address, &farmer_address, 1, 1_000);
+    client.mint_certificate(&1, &record);
+    
+    // El agricultor ya vendió 300 kg de CXO antes de la revocación
+    let units = CXO_UNITS_PER_KG as i128;
+    token_client.transfer(&farmer_address, &buyer_address, &(300 * units));
+    client.revoke_certificate(&verifier_address, &1, &BytesN::from_array(&env, &[66u8; 32]));
+    
+    let revocation = client.get_revocation(&1);
+    assert_eq!(revocation.cxo_clawed_back, 700 * units);
+    assert_eq!(revocation.cxo_unrecovered, 300 * units);
+    assert_eq!(token_client.balance(&farmer_address), 0);
+    assert_eq!(token_client.balance(&buyer_address), 300 * units);
+}
+
+#[test]
+fn test_revoke_certificate_after_nft_transfer_claws_back_from_farmer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (_, token_id) = register_verifier(&env, &client, &verifier_address);
+    let token_client = CarbonTokenClient::new(&env, &token_id);
+    let farmer_address = Address::generate(&env);
+    let buyer_address = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_address);
+    register_parcel(&env, &client, 2, &buyer_address);
+    
+    // El comprador tiene CXO de su propio certificado y compra solo el NFT del agricultor
+    client.mint_certificate(&1, &record(&env, &verifier_address, &farmer_address, 1, 1_000));
+    client.mint_certificate(&2, &record(&env, &verifier_address, &buyer_address, 2, 500));
+    client.transfer_certificate(&1, &farmer_address, &buyer_address);
+    client.revoke_certificate(&verifier_address, &1, &BytesN::from_array(&env, &[66u8; 32]));
+    
+    // ✅ Los CXO se recuperan del agricultor, a quien se acuñaron; el comprador conserva los suyos
+    let units = CXO_UNITS_PER_KG as i128;
+    let revocation = client.get_revocation(&1);
+    assert_eq!(revocation.cxo_clawed_back, 1_000 * units);
+    assert_eq!(revocation.cxo_unrecovered, 0);
+    assert_eq!(token_client.balance(&farmer_address), 0);
+    assert_eq!(token_client.balance(&buyer_address), 500 * units);
+    assert_eq!(client.get_certificate_owner(&1), buyer_address);
+}
+
+// ============================================================================
+// CICLO DE VIDA DEL CERTIFICADO
+// ============================================================================
+
+#[test]
+fn test_certificate_status_lifecycle() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    let (name, reason) = claim(&env);
+    
+    let verifier_address = Address::generate(&env);
+    let (admin, token_id) = register_verifier(&env, &client, &verifier_address);
+    client.grant_role(&admin, &admin, &Symbol::new(&env, REVOKER_ROLE));
+    let token_client = CarbonTokenClient::new(&env, &token_id);
+    
+    let record = record(&env, &verifier_address, &Address::generate(&env), 1, 1_000);
+    for parcel_id in 1..=3 {
+        register_parcel(&env, &client, parcel_id, &record.farmer_address);
+    }
+    assert_eq!(client.try_get_certificate_status(&1), Err(Ok(ContractError::NotFound)));
+    
+    client.mint_certificate(&1, &record);
+    client.mint_certificate(&2, &VerificationRecord { parcel_id: 2, ..record.clone() });
+    client.mint_certificate(&3, &VerificationRecord { parcel_id: 3, ..record.clone() });
+    assert_eq!(client.get_certificate_status(&1), CertificateStatus::Active);
+    
+    // ✅ Active -> Retired: el estado se conserva tras la quema
+    client.burn_certificate(&1, &record.farmer_address, &name, &reason);
+    assert_eq!(client.get_certificate_status(&1), CertificateStatus::Retired);
+    
+    // ✅ Active -> Revoked
+    client.revoke_certificate(&verifier_address, &2, &BytesN::from_array(&env, &[66u8; 32]));
+    assert_eq!(client.get_certificate_status(&2), CertificateStatus::Revoked);
+    
+    // ✅ Active -> Expired: sus CXO salen de circulación junto con su CO2e
+    client.expire_certificate(&admin, &3);
+    assert_eq!(client.get_certificate_status(&3), CertificateStatus::Expired);
+    assert_eq!(client.get_total_co2e(), 0);
+    assert_eq!(token_client.balance(&record.farmer_address), 0);
+    assert_eq!(token_client.total_supply(), client.get_total_co2e() as i128 * CXO_UNITS_PER_KG as i128);
+    
+    // ❌ Los estados finales no admiten más transiciones
+    assert_eq!(client.try_expire_certificate(&admin, &2), Err(Ok(ContractError::CertificateRevoked)));
+    assert_eq!(
+        client.try_revoke_certificate(&admin, &3, &BytesN::from_array(&env, &[66u8; 32])),
+        Err(Ok(ContractError::InvalidStatusTransition))
+    );
+    assert_eq!(client.try_burn_certificate(&3, &record.farmer_address, &name, &reason), Err(Ok(ContractError::InvalidStatusTransition)));
+    assert_eq!(
+        client.try_transfer_certificate(&3, &record.farmer_address, &Address::generate(&env)),
+        Err(Ok(ContractError::InvalidStatusTransition))
+    );
+    
+    // ❌ Un ID retirado no puede volver a acuñarse
+    assert_eq!(client.try_mint_certificate(&1, &record), Err(Ok(ContractError::AlreadyExists)));
+}
+
+#[test]
+fn test_expire_certificate_not_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
+    
+    let record = record(&env, &verifier_address, &Address::generate(&env), 1, 1_000);
+    register_parcel(&env, &client, 1, &record.farmer_address);
+    client.mint_certificate(&1, &record);
+    
+    let result = client.try_expire_certificate(&verifier_address, &1);
+    assert_eq!(result, Err(unauthorized()));
+    assert_eq!(client.get_certificate_status(&1), CertificateStatus::Active);
+}
+
+#[test]
+fn test_list_certificates_by_status() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (admin, _) = register_verifier(&env, &client, &verifier_address);
+    client.grant_role(&admin, &admin, &Symbol::new(&env, REVOKER_ROLE));
+    let farmer_address = Address::generate(&env);
+    
+    let record = record(&env, &verifier_address, &farmer_address, 1, 1_000);
+    for i in 1..=4u32 {
+        register_parcel(&env, &client, i, &farmer_address);
+        client.mint_certificate(&i, &VerificationRecord { parcel_id: i, ..record.clone() });
+    }
+    client.revoke_certificate(&admin, &2, &BytesN::from_array(&env, &[66u8; 32]));
+    client.expire_certificate(&admin, &4);
+    
+    let (active, total) = client.list_farmer_certs_by_status(&farmer_address, &CertificateStatus::Active, &0, &10);
+    assert_eq!(active, vec![&env, 1u32, 3]);
+    assert_eq!(total, 2);
+    
+    let (revoked, total) = client.list_verifier_certs_by_status(&verifier_address, &CertificateStatus::Revoked, &0, &10);
+    assert_eq!(revoked, vec![&env, 2u32]);
+    assert_eq!(total, 1);
+    
+    let (expired, _) = client.list_farmer_certs_by_status(&farmer_address, &CertificateStatus::Expired, &0, &10);
+    assert_eq!(expired, vec![&env, 4u32]);
+    
+    // Paginación sobre la lista filtrada
+    let (page, total) = client.list_farmer_certs_by_status(&farmer_address, &CertificateStatus::Active, &1, &1);
+    assert_eq!(page, vec![&env, 3u32]);
+    assert_eq!(total, 2);
+}
+
+// ============================================================================
+// QUÓRUM DE VERIFICADORES
+// ============================================================================
+
+fn setup_quorum(env: &Env, client: &CarbonCertifierClient, verifier_address: &Address) -> (Address, Address, Address, Address) {
+    let (admin, token_id) = register_verifier(env, client, verifier_address);
+    let second = Address::generate(env);
+    let third = Address::generate(env);
+    for verifier in [&second, &third] {
+        client.add_verifier(&admin, verifier, &String::from_str(env, "CONADESUCA"), &BytesN::from_array(env, &[8u8; 32]));
+    }
+    // 3 de N para certificados de 100 t o más
+    client.set_quorum_config(&admin, &QuorumConfig {
+        required_approvals: 3,
+        threshold_kg: 100_000,
+        proposal_ttl_ledgers: 100,
+    });
+    (admin, token_id, second, third)
+}
+
+#[test]
+fn test_quorum_proposal_mints_after_required_approvals() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (_, token_id, second, third) = setup_quorum(&env, &client, &verifier_address);
+    let token_client = CarbonTokenClient::new(&env, &token_id);
+    let farmer_address = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_address);
+    register_parcel(&env, &client, 2, &farmer_address);
+    let record = VerificationRecord {
+        hectares_not_burned: 200,
+        ..record(&env, &verifier_address, &farmer_address, 1, 500_000)
+    };
+    
+    // ❌ Un solo firmante no puede acuñar un certificado de alto valor
+    assert_eq!(client.try_mint_certificate(&1, &record), Err(Ok(ContractError::QuorumRequired)));
+    
+    // ✅ Propuesta pendiente: sin propietario, contadores ni CXO
+    assert!(!client.propose_certificate(&1, &record));
+    assert_eq!(client.try_get_certificate_data(&1), Err(Ok(ContractError::NotFound)));
+    assert_eq!(client.get_total_certificates(), 0);
+    assert_eq!(token_client.balance(&farmer_address), 0);
+    
+    assert!(!client.approve_proposal(&second, &1));
+    assert_eq!(client.try_approve_proposal(&second, &1), Err(Ok(ContractError::AlreadyApproved)));
+    assert_eq!(client.get_proposal(&1).approvals, vec![&env, verifier_address.clone(), second.clone()]);
+    
+    // ✅ La tercera firma alcanza el quórum y aplica la acuñación
+    assert!(client.approve_proposal(&third, &1));
+    assert_eq!(client.get_certificate_data(&1), record);
+    assert_eq!(client.get_certificate_owner(&1), farmer_address);
+    assert_eq!(client.get_total_certificates(), 1);
+    assert_eq!(client.get_total_co2e(), 500_000);
+    assert_eq!(token_client.balance(&farmer_address), 500_000 * CXO_UNITS_PER_KG as i128);
+    assert_eq!(client.try_get_proposal(&1), Err(Ok(ContractError::NotFound)));
+    
+    // Por debajo del umbral se acuña directamente
+    client.mint_certificate(&2, &VerificationRecord { co2e_kg: 99_999, parcel_id: 2, ..record });
+    assert_eq!(client.get_total_certificates(), 2);
+}
+
+#[test]
+fn test_quorum_proposal_expires() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (_, _, second, _) = setup_quorum(&env, &client, &verifier_address);
+    let record = VerificationRecord {
+        hectares_not_burned: 200,
+        ..record(&env, &verifier_address, &Address::generate(&env), 1, 500_000)
+    };
+    register_parcel(&env, &client, 1, &record.farmer_address);
+    client.propose_certificate(&1, &record);
+    assert_eq!(client.try_propose_certificate(&1, &record), Err(Ok(ContractError::AlreadyExists)));
+    assert_eq!(client.try_remove_expired_proposal(&1), Err(Ok(ContractError::InvalidInput)));
+    
+    // Pasada la vigencia, la propuesta ya no puede aprobarse
+    env.ledger().with_mut(|li| li.sequence_number += 101);
+    assert_eq!(client.try_approve_proposal(&second, &1), Err(Ok(ContractError::ProposalExpired)));
+    
+    // ✅ Cualquiera puede limpiar la propuesta vencida
+    client.remove_expired_proposal(&1);
+    assert_eq!(client.try_get_proposal(&1), Err(Ok(ContractError::NotFound)));
+    assert!(!client.propose_certificate(&1, &record));
+}
+
+#[test]
+fn test_mint_cannot_take_proposed_id() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (admin, token_id, second, third) = setup_quorum(&env, &client, &verifier_address);
+    let token_client = CarbonTokenClient::new(&env, &token_id);
+    let farmer_address = Address::generate(&env);
+    for parcel_id in 1..=3 {
+        register_parcel(&env, &client, parcel_id, &farmer_address);
+    }
+    let proposed = VerificationRecord {
+        hectares_not_burned: 200,
+        ..record(&env, &verifier_address, &farmer_address, 1, 500_000)
+    };
+    client.propose_certificate(&1, &proposed);
+    
+    // ❌ El ID de una propuesta vigente no puede acuñarse directamente
+    let small = record(&env, &verifier_address, &farmer_address, 2, 1_000);
+    assert_eq!(client.try_mint_certificate(&1, &small), Err(Ok(ContractError::AlreadyExists)));
+    assert_eq!(client.mint_next_certificate(&small), 2);
+    
+    // ✅ Al alcanzar el quórum se acuña una sola vez, sin sobrescribir ni duplicar
+    client.approve_proposal(&second, &1);
+    assert!(client.approve_proposal(&third, &1));
+    assert_eq!(client.get_certificate_data(&1), proposed);
+    assert_eq!(client.get_certificate_data(&2), small);
+    assert_eq!(client.get_total_certificates(), 2);
+    assert_eq!(client.get_total_co2e(), 501_000);
+    assert_eq!(token_client.balance(&farmer_address), 501_000 * CXO_UNITS_PER_KG as i128);
+    let (ids, total) = client.list_certificates_by_farmer(&farmer_address, &0, &10, &SortBy::CertificateId, &false);
+    assert_eq!((ids, total), (vec![&env, 1u32, 2u32], 2));
+    
+    // ❌ La aprobación final revalida el record contra la metodología vigente
+    let proposed = VerificationRecord { parcel_id: 3, ..proposed };
+    client.propose_certificate(&3, &proposed);
+    client.approve_proposal(&second, &3);
+    client.register_methodology(&admin, &MethodologyParams {
+        kg_co2e_per_hectare: 6_500,
+        buffer_bps: 0,
+        uncertainty_discount_bps: 0,
+    });
+    let result = client.try_approve_proposal(&third, &3);
+    assert_eq!(result, Err(Ok(ContractError::MethodologyVersionMismatch)));
+    assert_eq!(client.get_total_certificates(), 2);
+}
+
+#[test]
+fn test_quorum_requires_active_verifiers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (admin, _, second, _) = setup_quorum(&env, &client, &verifier_address);
+    let record = VerificationRecord {
+        hectares_not_burned: 200,
+        ..record(&env, &verifier_address, &Address::generate(&env), 1, 500_000)
+    };
+    register_parcel(&env, &client, 1, &record.farmer_address);
+    client.propose_certificate(&1, &record);
+    
+    let outsider = Address::generate(&env);
+    assert_eq!(client.try_approve_proposal(&outsider, &1), Err(Ok(ContractError::VerifierNotAuthorized)));
+    client.suspend_verifier(&admin, &second);
+    assert_eq!(client.try_approve_proposal(&second, &1), Err(Ok(ContractError::VerifierNotAuthorized)));
+    assert_eq!(client.try_approve_proposal(&outsider, &2), Err(Ok(ContractError::VerifierNotAuthorized)));
+}
+
+#[test]
+fn test_quorum_ignores_approvals_of_removed_verifiers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (admin, _, second, third) = setup_quorum(&env, &client, &verifier_address);
+    let record = VerificationRecord {
+        hectares_not_burned: 200,
+        ..record(&env, &verifier_address, &Address::generate(&env), 1, 500_000)
+    };
+    register_parcel(&env, &client, 1, &record.farmer_address);
+    client.propose_certificate(&1, &record);
+    assert!(!client.approve_proposal(&second, &1));
+    
+    // ❌ La firma de un verificador eliminado después de aprobar ya no cuenta
+    client.remove_verifier(&admin, &second);
+    assert!(!client.approve_proposal(&third, &1));
+    assert_eq!(client.get_proposal(&1).approvals.len(), 3);
+    assert_eq!(client.try_get_certificate_data(&1), Err(Ok(ContractError::NotFound)));
+    
+    // ✅ Un tercer verificador activo completa el quórum
+    let fourth = Address::generate(&env);
+    client.add_verifier(&admin, &fourth, &String::from_str(&env, "CONADESUCA"), &BytesN::from_array(&env, &[8u8; 32]));
+    assert!(client.approve_proposal(&fourth, &1));
+    assert_eq!(client.get_certificate_data(&1), record);
+}
+
+#[test]
+fn test_set_quorum_config_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (admin, _) = register_verifier(&env, &client, &verifier_address);
+    
+    assert_eq!(client.get_quorum_config().required_approvals, 1);
+    
+    let config = QuorumConfig { required_approvals: 0, threshold_kg: 0, proposal_ttl_ledgers: 100 };
+    assert_eq!(client.try_set_quorum_config(&admin, &config), Err(Ok(ContractError::InvalidInput)));
+    
+    let config = QuorumConfig { required_approvals: 2, threshold_kg: 0, proposal_ttl_ledgers: 100 };
+    assert_eq!(client.try_set_quorum_config(&verifier_address, &config), Err(unauthorized()));
+    client.set_quorum_config(&admin, &config);
+    assert_eq!(client.get_quorum_config(), config);
+}
+
+// ============================================================================
+// VENTANA DE IMPUGNACIÓN
+// ============================================================================
+
+#[test]
+fn test_finalize_certificate_after_unchallenged_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (admin, token_id) = register_verifier(&env, &client, &verifier_address);
+    client.grant_role(&admin, &admin, &Symbol::new(&env, REVOKER_ROLE));
+    let token_client = CarbonTokenClient::new(&env, &token_id);
+    let farmer_address = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_address);
+    client.set_challenge_window(&admin, &50);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_id.clone(),
+                (Symbol::new(&env, "challenge_window_updated_event"),).into_val(&env),
+                map![&env, (Symbol::new(&env, "ledgers"), 50u32)].into_val(&env),
+            ),
+        ]
+    );
+    
+    client.mint_certificate(&1, &record(&env, &verifier_address, &farmer_address, 1, 1_000));
+    
+    // ✅ Pendiente: NFT creado, sin CO2e acreditado ni CXO
+    assert_eq!(client.get_certificate_status(&1), CertificateStatus::Pending);
+    assert_eq!(client.get_certificate_owner(&1), farmer_address);
+    assert_eq!(client.get_total_co2e(), 0);
+    assert_eq!(token_client.balance(&farmer_address), 0);
+    assert_eq!(
+        client.try_transfer_certificate(&1, &farmer_address, &Address::generate(&env)),
+        Err(Ok(ContractError::InvalidStatusTransition))
+    );
+    
+    // ❌ No se puede finalizar con la ventana abierta
+    let deadline = client.get_challenge_deadline(&1);
+    assert_eq!(deadline, env.ledger().sequence() + 50);
+    assert_eq!(client.try_finalize_certificate(&1), Err(Ok(ContractError::ChallengeWindowOpen)));
+    
+    // ✅ Cerrada la ventana, cualquiera puede finalizar
+    env.ledger().with_mut(|li| li.sequence_number = deadline + 1);
+    client.finalize_certificate(&1);
+    assert_eq!(client.get_certificate_status(&1), CertificateStatus::Active);
+    assert_eq!(client.get_total_co2e(), 1_000);
+    assert_eq!(token_client.balance(&farmer_address), 1_000 * CXO_UNITS_PER_KG as i128);
+    assert_eq!(client.try_finalize_certificate(&1), Err(Ok(ContractError::InvalidStatusTransition)));
+    
+    // ❌ Tampoco puede impugnarse ya activo
+    let result = client.try_challenge_certificate(&admin, &1, &BytesN::from_array(&env, &[5u8; 32]));
+    assert_eq!(result, Err(Ok(ContractError::InvalidStatusTransition)));
+}
+
+#[test]
+fn test_challenged_certificate_goes_to_admin_resolution() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (admin, token_id) = register_verifier(&env, &client, &verifier_address);
+    client.grant_role(&admin, &admin, &Symbol::new(&env, REVOKER_ROLE));
+    let token_client = CarbonTokenClient::new(&env, &token_id);
+    let farmer_address = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_address);
+    register_parcel(&env, &client, 2, &farmer_address);
+    client.set_challenge_window(&admin, &50);
+    
+    client.mint_certificate(&1, &record(&env, &verifier_address, &farmer_address, 1, 1_000));
+    client.mint_certificate(&2, &record(&env, &verifier_address, &farmer_address, 2, 1_000));
+    
+    let evidence_hash = BytesN::from_array(&env, &[5u8; 32]);
+    client.challenge_certificate(&verifier_address, &1, &evidence_hash);
+    client.challenge_certificate(&admin, &2, &evidence_hash);
+    assert_eq!(client.get_challenge(&1).challenger, verifier_address);
+    assert_eq!(
+        client.try_challenge_certificate(&admin, &1, &evidence_hash),
+        Err(Ok(ContractError::CertificateChallenged))
+    );
+    
+    // ❌ Un certificado impugnado no se finaliza aunque cierre la ventana
+    env.ledger().with_mut(|li| li.sequence_number += 51);
+    assert_eq!(client.try_finalize_certificate(&1), Err(Ok(ContractError::CertificateChallenged)));
+    assert_eq!(client.try_resolve_challenge(&verifier_address, &1, &true), Err(unauthorized()));
+    
+    // ✅ Impugnación procedente: el certificado se revoca sin CXO acuñados
+    client.resolve_challenge(&admin, &1, &true);
+    assert_eq!(client.get_certificate_status(&1), CertificateStatus::Revoked);
+    assert_eq!(client.get_revocation(&1).reason_hash, evidence_hash);
+    
+    // ✅ Impugnación desestimada: el certificado se activa y acuña CXO
+    client.resolve_challenge(&admin, &2, &false);
+    assert_eq!(client.get_certificate_status(&2), CertificateStatus::Active);
+    assert_eq!(client.try_get_challenge(&2), Err(Ok(ContractError::NotFound)));
+    assert_eq!(client.get_total_co2e(), 1_000);
+    assert_eq!(token_client.balance(&farmer_address), 1_000 * CXO_UNITS_PER_KG as i128);
+}
+
+#[test]
+fn test_challenge_certificate_restrictions() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (admin, _) = register_verifier(&env, &client, &verifier_address);
+    let farmer_address = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_address);
+    client.set_challenge_window(&admin, &50);
+    
+    client.mint_certificate(&1, &record(&env, &verifier_address, &farmer_address, 1, 1_000));
+    let evidence_hash = BytesN::from_array(&env, &[5u8; 32]);
+    
+    // ❌ Solo verificadores activos o el admin
+    let outsider = Address::generate(&env);
+    assert_eq!(client.try_challenge_certificate(&outsider, &1, &evidence_hash), Err(Ok(ContractError::NotAuthorized)));
+    
+    // ❌ Fuera de la ventana
+    env.ledger().with_mut(|li| li.sequence_number += 51);
+    assert_eq!(
+        client.try_challenge_certificate(&verifier_address, &1, &evidence_hash),
+        Err(Ok(ContractError::ChallengeWindowClosed))
+    );
+    
+    // ✅ Un certificado pendiente también puede revocarse directamente
+    client.revoke_certificate(&verifier_address, &1, &evidence_hash);
+    assert_eq!(client.get_certificate_status(&1), CertificateStatus::Revoked);
+    assert_eq!(client.get_revocation(&1).co2e_kg_revoked, 0);
+    assert_eq!(client.get_total_co2e(), 0);
+}
+
+// ============================================================================
+// CÁLCULO DE CO2e CONADESUCA
+// ============================================================================
+
+#[test]
+fn test_estimate_co2e() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    
+    // 6.5 t CO2e por hectárea en Morelos, zafra 2024/25
+    client.set_emission_factor(&admin, &0, &symbol_short!("morelos"), &2024, &6_500);
+    assert_eq!(client.get_emission_factor(&0, &symbol_short!("morelos"), &2024), 6_500);
+    assert_eq!(client.estimate_co2e(&12, &symbol_short!("morelos"), &2024), 78_000);
+    
+    // Sin factor para otra zafra o región
+    assert_eq!(
+        client.try_estimate_co2e(&12, &symbol_short!("morelos"), &2023),
+        Err(Ok(ContractError::EmissionFactorNotSet))
+    );
+    assert_eq!(
+        client.try_estimate_co2e(&12, &symbol_short!("veracruz"), &2024),
+        Err(Ok(ContractError::EmissionFactorNotSet))
+    );
+}
+
+#[test]
+fn test_set_emission_factor_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let not_admin = Address::generate(&env);
+    client.initialize(&admin);
+    
+    let result = client.try_set_emission_factor(&not_admin, &0, &symbol_short!("morelos"), &2024, &6_500);
+    assert_eq!(result, Err(unauthorized()));
+    let result = client.try_set_emission_factor(&admin, &0, &symbol_short!("morelos"), &2024, &0);
+    assert_eq!(result, Err(Ok(ContractError::InvalidInput)));
+    let result = client.try_set_emission_factor(&admin, &1, &symbol_short!("morelos"), &2024, &6_500);
+    assert_eq!(result, Err(Ok(ContractError::MethodologyNotFound)));
+}
+
+#[test]
+fn test_mint_certificate_rejects_over_declared_co2e() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (admin, _) = register_verifier(&env, &client, &verifier_address);
+    client.set_emission_factor(&admin, &0, &symbol_short!("morelos"), &2024, &6_500);
+    
+    let record = record(&env, &verifier_address, &Address::generate(&env), 1, 65_001);
+    register_parcel(&env, &client, 1, &record.farmer_address);
+    
+    // ❌ 10 ha x 6.5 t = 65 t como máximo
+    assert_eq!(client.try_mint_certificate(&1, &record), Err(Ok(ContractError::Co2eExceedsEstimate)));
+    
+    // ❌ Zafra sin factor configurado
+    let result = client.try_mint_certificate(&1, &VerificationRecord { season: 2019, ..record.clone() });
+    assert_eq!(result, Err(Ok(ContractError::EmissionFactorNotSet)));
+    
+    // ✅ Exactamente la cota
+    client.mint_certificate(&1, &VerificationRecord { co2e_kg: 65_000, ..record });
+    assert_eq!(client.get_total_co2e(), 65_000);
+}
+
+// ============================================================================
+// REGISTRO VERSIONADO DE METODOLOGÍA
+// ============================================================================
+
+#[test]
+fn test_register_methodology_versions_are_immutable() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    assert_eq!(client.get_current_methodology_version(), 0);
+    
+    let v1 = MethodologyParams {
+        kg_co2e_per_hectare: 6_500,
+        buffer_bps: 1_000,
+        uncertainty_discount_bps: 500,
+    };
+    let v2 = MethodologyParams {
+        kg_co2e_per_hectare: 7_000,
+        buffer_bps: 2_000,
+        uncertainty_discount_bps: 0,
+    };
+    assert_eq!(client.register_methodology(&admin, &v1), 1);
+    assert_eq!(client.register_methodology(&admin, &v2), 2);
+    
+    // ✅ La versión nueva es la vigente y la anterior conserva sus valores
+    assert_eq!(client.get_current_methodology_version(), 2);
+    assert_eq!(client.get_methodology(&1), v1);
+    assert_eq!(client.get_methodology(&2), v2);
+    assert_eq!(client.try_get_methodology(&3), Err(Ok(ContractError::MethodologyNotFound)));
+    
+    // ❌ Solo el admin, con factor positivo y deducciones menores al 100%
+    let result = client.try_register_methodology(&Address::generate(&env), &v1);
+    assert_eq!(result, Err(unauthorized()));
+    let result = client.try_register_methodology(&admin, &MethodologyParams { kg_co2e_per_hectare: 0, ..v1.clone() });
+    assert_eq!(result, Err(Ok(ContractError::InvalidInput)));
+    let result = client.try_register_methodology(&admin, &MethodologyParams { buffer_bps: 9_500, ..v1 });
+    assert_eq!(result, Err(Ok(ContractError::InvalidInput)));
+    assert_eq!(client.get_current_methodology_version(), 2);
+}
+
+#[test]
+fn test_mint_certificate_applies_methodology_version() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (admin, _) = register_verifier(&env, &client, &verifier_address);
+    client.set_emission_factor(&admin, &0, &symbol_short!("morelos"), &2024, &6_500);
+    client.register_methodology(&admin, &MethodologyParams {
+        kg_co2e_per_hectare: 5_000,
+        buffer_bps: 1_000,
+        uncertainty_discount_bps: 500,
+    });
+    
+    // ✅ Sin factor regional de la versión 1 rige su factor por defecto: 10 ha x 5 t x 0.85
+    assert_eq!(client.estimate_co2e(&10, &symbol_short!("morelos"), &2024), 42_500);
+    
+    // ✅ Factor regional de la versión 1 con sus 15% de deducciones: 10 ha x 6.5 t x 0.85
+    client.set_emission_factor(&admin, &1, &symbol_short!("morelos"), &2024, &6_500);
+    assert_eq!(client.get_emission_factor(&1, &symbol_short!("morelos"), &2024), 6_500);
+    assert_eq!(client.estimate_co2e(&10, &symbol_short!("morelos"), &2024), 55_250);
+    assert_eq!(client.estimate_co2e(&10, &symbol_short!("morelos"), &2019), 42_500);
+    
+    // ✅ Cambiar el factor regional de la versión 0 no altera la versión 1
+    client.set_emission_factor(&admin, &0, &symbol_short!("morelos"), &2024, &9_000);
+    assert_eq!(client.estimate_co2e(&10, &symbol_short!("morelos"), &2024), 55_250);
+    
+    let record = record(&env, &verifier_address, &Address::generate(&env), 1, 55_250);
+    register_parcel(&env, &client, 1, &record.farmer_address);
+    
+    // ❌ El record debe referenciar la versión vigente
+    let result = client.try_mint_certificate(&1, &record);
+    assert_eq!(result, Err(Ok(ContractError::MethodologyVersionMismatch)));
+    
+    let record = VerificationRecord { methodology_version: 1, ..record };
+    let result = client.try_mint_certificate(&1, &VerificationRecord { co2e_kg: 55_251, ..record.clone() });
+    assert_eq!(result, Err(Ok(ContractError::Co2eExceedsEstimate)));
+    client.mint_certificate(&1, &record);
+    
+    // ✅ Una versión nueva no altera el certificado emitido bajo la anterior
+    client.register_methodology(&admin, &MethodologyParams {
+        kg_co2e_per_hectare: 5_000,
+        buffer_bps: 3_000,
+        uncertainty_discount_bps: 0,
+    });
+    assert_eq!(client.get_certificate_data(&1).methodology_version, 1);
+    assert_eq!(client.get_methodology(&1).buffer_bps, 1_000);
+    assert_eq!(client.estimate_co2e(&10, &symbol_short!("morelos"), &2024), 35_000);
+    assert_eq!(client.try_mint_certificate(&2, &record), Err(Ok(ContractError::MethodologyVersionMismatch)));
+}
+
+// ============================================================================
+// REGISTRO DE PARCELAS
+// ============================================================================
+
+#[test]
+fn test_register_parcel() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    let farmer_address = Address::generate(&env);
+    let geo_hash = BytesN::from_array(&env, &[3u8; 32]);
+    
+    client.register_parcel(&admin, &7, &farmer_address, &geo_hash, &12);
+    let parcel = client.get_parcel(&7);
+    assert_eq!(parcel.farmer, farmer_address);
+    assert_eq!(parcel.geo_hash, geo_hash);
+    assert_eq!(parcel.area_hectares, 12);
+    assert_eq!(client.get_parcel_certificate(&7, &2024), None);
+    
+    // ❌ Solo el admin, sin duplicados y con superficie positiva
+    let result = client.try_register_parcel(&Address::generate(&env), &8, &farmer_address, &geo_hash, &12);
+    assert_eq!(result, Err(unauthorized()));
+    let result = client.try_register_parcel(&admin, &7, &farmer_address, &geo_hash, &12);
+    assert_eq!(result, Err(Ok(ContractError::AlreadyExists)));
+    let result = client.try_register_parcel(&admin, &8, &farmer_address, &geo_hash, &0);
+    assert_eq!(result, Err(Ok(ContractError::InvalidInput)));
+    assert_eq!(client.try_get_parcel(&8), Err(Ok(ContractError::ParcelNotFound)));
+}
+
+#[test]
+fn test_mint_certificate_prevents_parcel_double_counting() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (admin, _) = register_verifier(&env, &client, &verifier_address);
+    client.set_emission_factor(&admin, &0, &symbol_short!("morelos"), &2025, &TEST_KG_CO2E_PER_HECTARE);
+    let farmer_address = Address::generate(&env);
+    client.register_parcel(&admin, &1, &farmer_address, &BytesN::from_array(&env, &[3u8; 32]), &10);
+    client.register_parcel(&admin, &2, &Address::generate(&env), &BytesN::from_array(&env, &[4u8; 32]), &10);
+    
+    let record = record(&env, &verifier_address, &farmer_address, 1, 1_000);
+    
+    // ❌ Parcela inexistente, ajena o con más hectáreas de las registradas
+    let result = client.try_mint_certificate(&1, &VerificationRecord { parcel_id: 9, ..record.clone() });
+    assert_eq!(result, Err(Ok(ContractError::ParcelNotFound)));
+    let result = client.try_mint_certificate(&1, &VerificationRecord { parcel_id: 2, ..record.clone() });
+    assert_eq!(result, Err(Ok(ContractError::ParcelFarmerMismatch)));
+    let result = client.try_mint_certificate(&1, &VerificationRecord { hectares_not_burned: 11, ..record.clone() });
+    assert_eq!(result, Err(Ok(ContractError::HectaresExceedParcelArea)));
+    
+    // ✅ Un certificado por parcela y zafra
+    client.mint_certificate(&1, &record);
+    assert_eq!(client.get_parcel_certificate(&1, &2024), Some(1));
+    assert_eq!(client.try_mint_certificate(&2, &record), Err(Ok(ContractError::ParcelSeasonAlreadyCertified)));
+    
+    // ✅ La misma parcela puede certificarse en la zafra siguiente
+    client.mint_certificate(&2, &VerificationRecord { season: 2025, ..record });
+    assert_eq!(client.get_parcel_certificate(&1, &2025), Some(2));
+}
+
+#[test]
+fn test_quorum_proposals_compete_for_parcel_season() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (_, _, second, third) = setup_quorum(&env, &client, &verifier_address);
+    let farmer_address = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_address);
+    let record = VerificationRecord {
+        hectares_not_burned: 200,
+        ..record(&env, &verifier_address, &farmer_address, 1, 500_000)
+    };
+    
+    // Dos propuestas para la misma parcela y zafra: solo la primera en alcanzar quórum se acuña
+    client.propose_certificate(&1, &record);
+    client.propose_certificate(&2, &record);
+    client.approve_proposal(&second, &1);
+    assert!(client.approve_proposal(&third, &1));
+    
+    client.approve_proposal(&second, &2);
+    assert_eq!(client.try_approve_proposal(&third, &2), Err(Ok(ContractError::ParcelSeasonAlreadyCertified)));
+    assert_eq!(client.get_total_certificates(), 1);
+}
+
+// ============================================================================
+// VINTAGE, PERIODO DE MONITOREO Y UBICACIÓN
+// ============================================================================
+
+#[test]
+fn test_list_certificates_by_vintage_and_project() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    let (name, reason) = claim(&env);
+    
+    let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
+    let farmer_address = Address::generate(&env);
+    for parcel_id in 1..=3 {
+        register_parcel(&env, &client, parcel_id, &farmer_address);
+    }
+    
+    let record = record(&env, &verifier_address, &farmer_address, 1, 1_000);
+    
+    // ❌ Periodo de monitoreo invertido
+    let result = client.try_mint_certificate(&1, &VerificationRecord { monitoring_end: 1_704_067_200, ..record.clone() });
+    assert_eq!(result, Err(Ok(ContractError::InvalidInput)));
+    
+    client.mint_certificate(&1, &record);
+    client.mint_certificate(&2, &VerificationRecord { parcel_id: 2, vintage: 2023, ..record.clone() });
+    client.mint_certificate(&3, &VerificationRecord {
+        parcel_id: 3,
+        project_id: symbol_short!("casasano"),
+        municipality: String::from_str(&env, "Cuautla"),
+        ..record.clone()
+    });
+    
+    assert_eq!(client.list_certificates_by_vintage(&2024, &0, &10), (vec![&env, 1u32, 3], 2));
+    assert_eq!(client.list_certificates_by_project(&symbol_short!("ezapata"), &0, &10), (vec![&env, 1u32, 2], 2));
+    assert_eq!(client.filter_project_by_vintage(&symbol_short!("ezapata"), &2023, &0, &10), (vec![&env, 2u32], 1));
+    assert_eq!(client.get_certificate_data(&3).municipality, String::from_str(&env, "Cuautla"));
+    
+    // ✅ Los certificados retirados salen de los índices
+    client.burn_certificate(&1, &farmer_address, &name, &reason);
+    assert_eq!(client.list_certificates_by_vintage(&2024, &0, &10), (vec![&env, 3u32], 1));
+    assert_eq!(client.list_certificates_by_project(&symbol_short!("ezapata"), &0, &10), (vec![&env, 2u32], 1));
+}
+
+#[test]
+fn test_migrate_legacy_certificate_record() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (admin, _) = register_verifier(&env, &client, &verifier_address);
+    client.grant_role(&admin, &admin, &Symbol::new(&env, UPGRADER_ROLE));
+    let farmer_address = Address::generate(&env);
+    
+    // Certificado almacenado con el formato original (1 t CO2e)
+    let legacy = LegacyVerificationRecord {
+        verifier_address: verifier_address.clone(),
+        farmer_address: farmer_address.clone(),
+        hectares_not_burned: 10,
+        co2e_tons: 1,
+        metadata_hash: BytesN::from_array(&env, &[0u8; 32]),
+    };
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().set(&DataKey::Certificates(1), &legacy);
+    });
+    
+    let record = record(&env, &verifier_address, &farmer_address, 1, 1_000);
+    
+    // ❌ Solo con el contrato pausado
+    assert!(client.try_migrate_certificate_record(&admin, &1, &record).is_err());
+    client.pause(&admin);
+    
+    // ❌ Solo el admin, sobre certificados existentes y sin alterar los datos originales
+    let result = client.try_migrate_certificate_record(&verifier_address, &1, &record);
+    assert_eq!(result, Err(unauthorized()));
+    let result = client.try_migrate_certificate_record(&admin, &2, &record);
+    assert_eq!(result, Err(Ok(ContractError::NotFound)));
+    let result = client.try_migrate_certificate_record(&admin, &1, &VerificationRecord { co2e_kg: 1, ..record.clone() });
+    assert_eq!(result, Err(Ok(ContractError::InvalidInput)));
+    let result = client.try_migrate_certificate_record(&admin, &1, &VerificationRecord { hectares_not_burned: 12, ..record.clone() });
+    assert_eq!(result, Err(Ok(ContractError::InvalidInput)));
+    
+    // ❌ La parcela debe estar registrada, ser del agricultor y cubrir las hectáreas
+    let result = client.try_migrate_certificate_record(&admin, &1, &record);
+    assert_eq!(result, Err(Ok(ContractError::ParcelNotFound)));
+    register_parcel(&env, &client, 1, &Address::generate(&env));
+    let result = client.try_migrate_certificate_record(&admin, &1, &record);
+    assert_eq!(result, Err(Ok(ContractError::ParcelFarmerMismatch)));
+    client.register_parcel(&admin, &2, &farmer_address, &BytesN::from_array(&env, &[3u8; 32]), &5);
+    let result = client.try_migrate_certificate_record(&admin, &1, &VerificationRecord { parcel_id: 2, ..record.clone() });
+    assert_eq!(result, Err(Ok(ContractError::HectaresExceedParcelArea)));
+    register_parcel(&env, &client, 3, &farmer_address);
+    let record = VerificationRecord { parcel_id: 3, ..record };
+    
+    // ✅ Migrado: 1 t pasa a 1,000 kg, legible con el formato nuevo e indexado por vintage y proyecto
+    client.migrate_certificate_record(&admin, &1, &record);
+    assert_eq!(client.get_certificate_data(&1), record);
+    assert_eq!(client.list_certificates_by_vintage(&2024, &0, &10), (vec![&env, 1u32], 1));
+    assert_eq!(client.list_certificates_by_project(&symbol_short!("ezapata"), &0, &10), (vec![&env, 1u32], 1));
+    
+    let result = client.try_migrate_certificate_record(&admin, &1, &record);
+    assert_eq!(result, Err(Ok(ContractError::AlreadyExists)));
+    client.unpause(&admin);
+    
+    // ❌ DOBLE CONTEO: La migración reserva la (parcela, zafra); no puede volver a acuñarse
+    let result = client.try_mint_certificate(&2, &record);
+    assert_eq!(result, Err(Ok(ContractError::ParcelSeasonAlreadyCertified)));
+}
+
+#[test]
+fn test_burn_migrated_legacy_certificate() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    let (name, reason) = claim(&env);
+    
+    let verifier_address = Address::generate(&env);
+    let (admin, token_id) = register_verifier(&env, &client, &verifier_address);
+    client.grant_role(&admin, &admin, &Symbol::new(&env, UPGRADER_ROLE));
+    let token_client = CarbonTokenClient::new(&env, &token_id);
+    let farmer_address = Address::generate(&env);
+    
+    register_parcel(&env, &client, 1, &farmer_address);
+    
+    // Certificado de 2 t y sus 2 CXO enteros, con los formatos originales
+    let record = record(&env, &verifier_address, &farmer_address, 1, 2_000);
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().set(&DataKey::Certificates(1), &LegacyVerificationRecord {
+            verifier_address: verifier_address.clone(),
+            farmer_address: farmer_address.clone(),
+            hectares_not_burned: 10,
+            co2e_tons: 2,
+            metadata_hash: record.metadata_hash.clone(),
+        });
+        env.storage().persistent().set(&DataKey::CertificateOwner(1), &farmer_address);
+    });
+    env.as_contract(&token_id, || {
+        env.storage().instance().remove(&carbon_token::DataKey::SchemaVersion);
+        env.storage().persistent().set(&carbon_token::DataKey::Balance(farmer_address.clone()), &2i128);
+        env.storage().instance().set(&carbon_token::DataKey::TotalMinted, &2i128);
+    });
+    client.pause(&admin);
+    client.migrate_certificate_record(&admin, &1, &record);
+    client.unpause(&admin);
+    
+    // ❌ Sin migrar los balances, 2,000 kg piden más CXO de los que hay
+    assert_eq!(client.try_burn_certificate(&1, &farmer_address, &name, &reason), Err(Ok(ContractError::TokenBurnFailed)));
+    
+    // ✅ Con los balances en 7 decimales el certificado migrado puede retirarse
+    token_client.pause(&admin);
+    token_client.migrate_balances(&vec![&env, farmer_address.clone()]);
+    token_client.finish_migration();
+    token_client.unpause(&admin);
+    
+    let receipt_id = client.burn_certificate(&1, &farmer_address, &name, &reason);
+    assert_eq!(client.get_retirement_receipt(&receipt_id).co2e_kg, 2_000);
+    assert_eq!(token_client.balance(&farmer_address), 0);
+    assert_eq!(token_client.total_supply(), 0);
+}
+
+// ============================================================================
+// IDS AUTOINCREMENTALES
+// ============================================================================
+
+#[test]
+fn test_mint_next_certificate_allocates_sequential_ids() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (admin, _) = register_verifier(&env, &client, &verifier_address);
+    let farmer_address = Address::generate(&env);
+    for parcel_id in 1..=4 {
+        register_parcel(&env, &client, parcel_id, &farmer_address);
+    }
+    
+    let record = record(&env, &verifier_address, &farmer_address, 1, 1_000);
+    
+    assert_eq!(client.next_certificate_id(), 1);
+    assert_eq!(client.mint_next_certificate(&record), 1);
+    assert_eq!(client.next_certificate_id(), 2);
+    
+    // ✅ El ID explícito sigue disponible y la secuencia lo salta
+    client.mint_certificate(&2, &VerificationRecord { parcel_id: 2, ..record.clone() });
+    assert_eq!(client.next_certificate_id(), 3);
+    
+    // ✅ Los IDs reservados por una propuesta de quórum tampoco se asignan
+    client.set_quorum_config(&admin, &QuorumConfig {
+        required_approvals: 2,
+        threshold_kg: 1_000,
+        proposal_ttl_ledgers: 100,
+    });
+    client.propose_certificate(&3, &VerificationRecord { parcel_id: 3, ..record.clone() });
+    assert_eq!(client.next_certificate_id(), 4);
+    
+    let id = client.mint_next_certificate(&VerificationRecord { parcel_id: 4, co2e_kg: 999, ..record });
+    assert_eq!(id, 4);
+    assert_eq!(client.get_certificate_data(&4).co2e_kg, 999);
+    assert_eq!(client.get_total_certificates(), 3);
+}
+
+#[test]
+fn test_explicit_high_id_does_not_exhaust_sequence() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
+    let farmer_address = Address::generate(&env);
+    for parcel_id in 1..=3 {
+        register_parcel(&env, &client, parcel_id, &farmer_address);
+    }
+    
+    // ✅ El último ID se acuña con ID explícito
+    client.mint_certificate(&u32::MAX, &record(&env, &verifier_address, &farmer_address, 1, 1_000));
+    assert_eq!(client.get_certificate_owner(&u32::MAX), farmer_address);
+    
+    // ✅ La secuencia no se mueve: los IDs asignados siguen desde el principio
+    assert_eq!(client.next_certificate_id(), 1);
+    assert_eq!(client.mint_next_certificate(&record(&env, &verifier_address, &farmer_address, 2, 1_000)), 1);
+    assert_eq!(client.split_certificate(&u32::MAX, &vec![&env, 400u128, 600u128]), vec![&env, 2u32, 3]);
+    assert_eq!(client.next_certificate_id(), 4);
+    assert_eq!(client.get_total_certificates(), 3);
+    
+    // ❌ La secuencia solo se agota cuando ella misma llega al último ID libre
+    env.as_contract(&contract_id, || {
+        env.storage().instance().set(&DataKey::NextCertificateId, &(u32::MAX - 1));
+    });
+    let record = record(&env, &verifier_address, &farmer_address, 3, 1_000);
+    assert_eq!(client.mint_next_certificate(&record), u32::MAX - 1);
+    assert_eq!(client.try_next_certificate_id(), Err(Ok(ContractError::CertificateIdsExhausted)));
+}
+
+// ============================================================================
+// ACUÑACIÓN POR LOTES
+// ============================================================================
+
+#[test]
+fn test_mint_certificates_batch() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_a = Address::generate(&env);
+    let verifier_b = Address::generate(&env);
+    let (admin, token_id) = register_verifier(&env, &client, &verifier_a);
+    client.add_verifier(&admin, &verifier_b, &String::from_str(&env, "ULPCA"), &BytesN::from_array(&env, &[8u8; 32]));
+    let token_client = CarbonTokenClient::new(&env, &token_id);
+    
+    let farmer_1 = Address::generate(&env);
+    let farmer_2 = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_1);
+    register_parcel(&env, &client, 2, &farmer_1);
+    register_parcel(&env, &client, 3, &farmer_2);
+    
+    client.mint_certificates_batch(&vec![
+        &env,
+        (1u32, record(&env, &verifier_a, &farmer_1, 1, 1_000)),
+        (2u32, record(&env, &verifier_b, &farmer_1, 2, 500)),
+        (3u32, record(&env, &verifier_a, &farmer_2, 3, 200)),
+    ]);
+    
+    // ✅ Una sola autorización por verificador
+    let auths = env.auths();
+    assert_eq!(auths.iter().filter(|(address, _)| *address == verifier_a).count(), 1);
+    assert_eq!(auths.iter().filter(|(address, _)| *address == verifier_b).count(), 1);
+    
+    // ✅ Un evento por certificado
+    let minted_events = env.events().all().filter_by_contract(&contract_id).events().len();
+    assert_eq!(minted_events, 3);
+    
+    // ✅ Contadores, índices y CXO agregados
+    assert_eq!(client.get_total_certificates(), 3);
+    assert_eq!(client.get_total_co2e(), 1_700);
+    assert_eq!(client.list_certificates_by_farmer(&farmer_1, &0, &10, &SortBy::CertificateId, &false).0, vec![&env, 1u32, 2]);
+    assert_eq!(client.list_certificates_by_verifier(&verifier_a, &0, &10).0, vec![&env, 1u32, 3]);
+    assert_eq!(client.list_certificates_by_vintage(&2024, &0, &10).1, 3);
+    assert_eq!(client.get_certificate_owner(&3), farmer_2);
+    assert_eq!(token_client.balance(&farmer_1), 1_500 * CXO_UNITS_PER_KG as i128);
+    assert_eq!(token_client.balance(&farmer_2), 200 * CXO_UNITS_PER_KG as i128);
+    assert_eq!(client.next_certificate_id(), 4);
+}
+
+#[test]
+fn test_mint_certificates_batch_is_all_or_nothing() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (_, token_id) = register_verifier(&env, &client, &verifier_address);
+    let token_client = CarbonTokenClient::new(&env, &token_id);
+    let farmer_address = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_address);
+    register_parcel(&env, &client, 2, &farmer_address);
+    
+    let result = client.try_mint_certificates_batch(&Vec::new(&env));
+    assert_eq!(result, Err(Ok(ContractError::InvalidInput)));
+    
+    // ❌ La misma parcela y zafra dos veces en el lote
+    let result = client.try_mint_certificates_batch(&vec![
+        &env,
+        (1u32, record(&env, &verifier_address, &farmer_address, 1, 1_000)),
+        (2u32, record(&env, &verifier_address, &farmer_address, 1, 1_000)),
+    ]);
+    assert_eq!(result, Err(Ok(ContractError::ParcelSeasonAlreadyCertified)));
+    
+    // ❌ ID repetido dentro del lote
+    let result = client.try_mint_certificates_batch(&vec![
+        &env,
+        (1u32, record(&env, &verifier_address, &farmer_address, 1, 1_000)),
+        (1u32, record(&env, &verifier_address, &farmer_address, 2, 1_000)),
+    ]);
+    assert_eq!(result, Err(Ok(ContractError::AlreadyExists)));
+    
+    // ✅ Ninguna escritura persiste
+    assert!(client.try_get_certificate_data(&1).is_err());
+    assert_eq!(client.get_parcel_certificate(&1, &2024), None);
+    assert_eq!(client.get_total_certificates(), 0);
+    assert_eq!(client.get_total_co2e(), 0);
+    assert_eq!(token_client.balance(&farmer_address), 0);
+}
+
+// ============================================================================
+// TTL DEL ALMACENAMIENTO
+// ============================================================================
+
+fn persistent_ttl(env: &Env, contract_id: &Address, key: &DataKey) -> u32 {
+    env.as_contract(contract_id, || env.storage().persistent().get_ttl(key))
+}
+
+#[test]
+fn test_certificate_ttl_extended_and_bumped() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    register_verifier(&env, &client, &verifier_address);
+    let farmer_address = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_address);
+    
+    client.mint_certificate(&1, &record(&env, &verifier_address, &farmer_address, 1, 1_000));
+    
+    // ✅ La acuñación deja el certificado, su propietario y los índices con el TTL por defecto
+    let extend_to = client.get_ttl_config().extend_to_ledgers;
+    let cert_key = DataKey::Certificates(1);
+    let owner_key = DataKey::CertificateOwner(1);
+    let list_key = DataKey::FarmerCertList(farmer_address.clone());
+    assert_eq!(persistent_ttl(&env, &contract_id, &cert_key), extend_to);
+    assert_eq!(persistent_ttl(&env, &contract_id, &owner_key), extend_to);
+    assert_eq!(persistent_ttl(&env, &contract_id, &list_key), extend_to);
+    
+    // Sin tocarlo, el TTL cae por debajo del umbral
+    env.ledger().with_mut(|li| li.sequence_number += 1_600_000);
+    assert_eq!(persistent_ttl(&env, &contract_id, &cert_key), extend_to - 1_600_000);
+    
+    // ✅ Cualquiera puede renovar el certificado y los índices de una cuenta
+    client.bump_certificate(&1);
+    assert_eq!(persistent_ttl(&env, &contract_id, &cert_key), extend_to);
+    assert_eq!(persistent_ttl(&env, &contract_id, &owner_key), extend_to);
+    assert_eq!(persistent_ttl(&env, &contract_id, &list_key), extend_to - 1_600_000);
+    
+    client.bump_account(&farmer_address);
+    assert_eq!(persistent_ttl(&env, &contract_id, &list_key), extend_to);
+    
+    assert_eq!(client.try_bump_certificate(&99), Err(Ok(ContractError::NotFound)));
+}
+
+#[test]
+fn test_certificate_reads_extend_ttl() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (admin, _) = register_verifier(&env, &client, &verifier_address);
+    let farmer_address = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_address);
+    client.mint_certificate(&1, &record(&env, &verifier_address, &farmer_address, 1, 1_000));
+    
+    // ✅ VALIDACIÓN: La extensión debe superar al umbral y no exceder el máximo de la red
+    let invalid = TtlConfig { threshold_ledgers: 1_000, extend_to_ledgers: 1_000 };
+    assert_eq!(client.try_set_ttl_config(&admin, &invalid), Err(Ok(ContractError::InvalidInput)));
+    let too_long = TtlConfig { threshold_ledgers: 1_000, extend_to_ledgers: u32::MAX };
+    assert_eq!(client.try_set_ttl_config(&admin, &too_long), Err(Ok(ContractError::InvalidInput)));
+    let outsider = Address::generate(&env);
+    let config = TtlConfig { threshold_ledgers: 2_500_000, extend_to_ledgers: 3_000_000 };
+    assert_eq!(client.try_set_ttl_config(&outsider, &config), Err(unauthorized()));
+    
+    client.set_ttl_config(&admin, &config);
+    assert_eq!(client.get_ttl_config(), config);
+    
+    // ✅ Una simple lectura renueva el certificado y su propietario con la nueva configuración
+    client.get_certificate_owner(&1);
+    assert_eq!(persistent_ttl(&env, &contract_id, &DataKey::Certificates(1)), 3_000_000);
+    assert_eq!(persistent_ttl(&env, &contract_id, &DataKey::CertificateOwner(1)), 3_000_000);
+    
+    let list_key = DataKey::FarmerCertList(farmer_address.clone());
+    assert!(persistent_ttl(&env, &contract_id, &list_key) < 3_000_000);
+    client.list_certificates_by_farmer(&farmer_address, &0, &10, &SortBy::CertificateId, &false);
+    assert_eq!(persistent_ttl(&env, &contract_id, &list_key), 3_000_000);
+}
+
+#[test]
+fn test_registry_and_receipt_ttl_extended_and_bumped() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (admin, _) = register_verifier(&env, &client, &verifier_address);
+    client.grant_role(&admin, &admin, &Symbol::new(&env, REVOKER_ROLE));
+    let farmer_address = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_address);
+    client.mint_certificate(&1, &record(&env, &verifier_address, &farmer_address, 1, 1_000));
+    let beneficiary = Address::generate(&env);
+    client.retire_partial(&1, &200, &beneficiary, &String::from_str(&env, "Grupo Modelo"), &String::from_str(&env, "Compensación 2024"));
+    client.revoke_certificate(&admin, &1, &BytesN::from_array(&env, &[66u8; 32]));
+    
+    // ✅ Las escrituras dejan registros, reserva, recibos y revocación con el TTL por defecto
+    let extend_to = client.get_ttl_config().extend_to_ledgers;
+    let verifier_key = DataKey::Verifier(verifier_address.clone());
+    let parcel_key = DataKey::Parcel(1);
+    let parcel_season_key = DataKey::ParcelSeason(1, 2024);
+    let retired_key = DataKey::CertificateRetired(1);
+    let revocation_key = DataKey::Revocation(1);
+    let receipt_key = DataKey::RetirementReceipt(1);
+    let receipts_key = DataKey::BeneficiaryReceiptList(beneficiary.clone());
+    for key in [&verifier_key, &parcel_key, &parcel_season_key, &retired_key, &revocation_key, &receipt_key, &receipts_key] {
+        assert_eq!(persistent_ttl(&env, &contract_id, key), extend_to);
+    }
+    
+    env.ledger().with_mut(|li| li.sequence_number += 1_600_000);
+    
+    // ✅ Renovar el certificado cubre su parcela, su reserva, su retiro y su revocación
+    client.bump_certificate(&1);
+    for key in [&parcel_key, &parcel_season_key, &retired_key, &revocation_key] {
+        assert_eq!(persistent_ttl(&env, &contract_id, key), extend_to);
+    }
+    assert_eq!(persistent_ttl(&env, &contract_id, &verifier_key), extend_to - 1_600_000);
+    
+    // ✅ Renovar una cuenta cubre su registro de verificador y sus recibos
+    client.bump_account(&verifier_address);
+    client.bump_account(&beneficiary);
+    assert_eq!(persistent_ttl(&env, &contract_id, &verifier_key), extend_to);
+    assert_eq!(persistent_ttl(&env, &contract_id, &receipts_key), extend_to);
+    
+    // ✅ Leer un recibo lo renueva
+    assert_eq!(persistent_ttl(&env, &contract_id, &receipt_key), extend_to - 1_600_000);
+    client.get_retirement_receipt(&1);
+    assert_eq!(persistent_ttl(&env, &contract_id, &receipt_key), extend_to);
+}
+
+#[test]
+fn test_proposal_challenge_and_factor_ttl_extended_and_bumped() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (admin, _, _, _) = setup_quorum(&env, &client, &verifier_address);
+    let farmer_address = Address::generate(&env);
+    for parcel_id in 1..=3 {
+        register_parcel(&env, &client, parcel_id, &farmer_address);
+    }
+    
+    // Certificado dividido, propuesta de quórum y certificado impugnado
+    client.mint_certificate(&3, &record(&env, &verifier_address, &farmer_address, 3, 1_000));
+    client.split_certificate(&3, &vec![&env, 600u128, 400]);
+    client.propose_certificate(&1, &VerificationRecord {
+        hectares_not_burned: 200,
+        ..record(&env, &verifier_address, &farmer_address, 1, 500_000)
+    });
+    client.set_challenge_window(&admin, &50);
+    client.mint_certificate(&2, &record(&env, &verifier_address, &farmer_address, 2, 1_000));
+    client.challenge_certificate(&verifier_address, &2, &BytesN::from_array(&env, &[5u8; 32]));
+    
+    // ✅ Las escrituras dejan factor, propuesta, ventana, impugnación y enlace al padre con el TTL por defecto
+    let extend_to = client.get_ttl_config().extend_to_ledgers;
+    let factor_key = DataKey::EmissionFactor(0, symbol_short!("morelos"), 2024);
+    let proposal_key = DataKey::Proposal(1);
+    let deadline_key = DataKey::ChallengeDeadline(2);
+    let challenge_key = DataKey::Challenge(2);
+    let parent_key = DataKey::CertificateParent(4);
+    for key in [&factor_key, &proposal_key, &deadline_key, &challenge_key, &parent_key] {
+        assert_eq!(persistent_ttl(&env, &contract_id, key), extend_to);
+    }
+    
+    env.ledger().with_mut(|li| li.sequence_number += 1_600_000);
+    
+    // ✅ Renovar un certificado propuesto cubre la propuesta y el factor de su región y zafra
+    client.bump_certificate(&1);
+    assert_eq!(persistent_ttl(&env, &contract_id, &proposal_key), extend_to);
+    assert_eq!(persistent_ttl(&env, &contract_id, &factor_key), extend_to);
+    assert_eq!(persistent_ttl(&env, &contract_id, &challenge_key), extend_to - 1_600_000);
+    
+    // ✅ Renovar un certificado pendiente cubre su ventana y su impugnación; un hijo, su enlace
+    client.bump_certificate(&2);
+    client.bump_certificate(&4);
+    for key in [&deadline_key, &challenge_key, &parent_key] {
+        assert_eq!(persistent_ttl(&env, &contract_id, key), extend_to);
+    }
+}
+
+// ============================================================================
+// ACTUALIZACIÓN DEL CONTRATO Y MIGRACIÓN DE ESQUEMA
+// ============================================================================
+
+#[test]
+fn test_upgrade_requires_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    let wasm_hash = BytesN::from_array(&env, &[1u8; 32]);
+    
+    // ❌ El admin no recibe `upgrader` al inicializar: se otorga de forma explícita
+    assert_eq!(client.try_upgrade(&admin, &wasm_hash), Err(unauthorized()));
+    client.grant_role(&admin, &admin, &Symbol::new(&env, UPGRADER_ROLE));
+    
+    // ✅ Un contrato nuevo nace en el esquema vigente
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+    client.pause(&admin);
+    assert_eq!(client.try_finish_migration(&admin), Err(Ok(ContractError::AlreadyExists)));
+    client.unpause(&admin);
+    
+    // ❌ Solo el rol `upgrader` puede actualizar el código
+    let outsider = Address::generate(&env);
+    assert_eq!(client.try_upgrade(&outsider, &wasm_hash), Err(unauthorized()));
+}
+
+#[test]
+fn test_migrate_legacy_records_in_batches() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (admin, _) = register_verifier(&env, &client, &verifier_address);
+    let farmer_address = Address::generate(&env);
+    
+    register_parcel(&env, &client, 1, &farmer_address);
+    register_parcel(&env, &client, 2, &farmer_address);
+    
+    // Despliegue original (sin marcador de esquema) con dos certificados de 1 t y
+    // 2 t y el total de CO2e en toneladas
+    let records = vec![
+        &env,
+        (1u32, record(&env, &verifier_address, &farmer_address, 1, 1_000)),
+        (2u32, record(&env, &verifier_address, &farmer_address, 2, 2_000)),
+    ];
+    env.as_contract(&contract_id, || {
+        env.storage().instance().remove(&DataKey::SchemaVersion);
+        for (id, record) in records.iter() {
+            env.storage().persistent().set(&DataKey::Certificates(id), &LegacyVerificationRecord {
+                verifier_address: record.verifier_address,
+                farmer_address: record.farmer_address,
+                hectares_not_burned: record.hectares_not_burned,
+                co2e_tons: record.co2e_kg / KG_PER_TON,
+                metadata_hash: record.metadata_hash,
+            });
+        }
+        env.storage().instance().set(&DataKey::TotalCO2e, &3u128);
+    });
+    assert_eq!(client.get_schema_version(), 1);
+    
+    // El admin de un esquema anterior aún no tiene roles operativos
+    let upgrader = Symbol::new(&env, UPGRADER_ROLE);
+    let config_manager = Symbol::new(&env, CONFIG_MANAGER_ROLE);
+    client.revoke_role(&admin, &admin, &config_manager);
+    assert_eq!(client.try_migrate(&admin, &records), Err(unauthorized()));
+    
+    // ✅ Se otorga `upgrader` para migrar
+    client.grant_role(&admin, &admin, &upgrader);
+    
+    // ❌ Solo con el contrato pausado
+    assert!(client.try_migrate(&admin, &records).is_err());
+    assert!(client.try_finish_migration(&admin).is_err());
+    assert_eq!(client.get_schema_version(), 1);
+    client.pause(&admin);
+    
+    // ❌ Lotes vacíos o con un record alterado no migran nada
+    let result = client.try_migrate(&admin, &Vec::new(&env));
+    assert_eq!(result, Err(Ok(ContractError::InvalidInput)));
+    let mut tampered = records.clone();
+    tampered.set(1, (2, record(&env, &verifier_address, &farmer_address, 2, 9_000)));
+    assert_eq!(client.try_migrate(&admin, &tampered), Err(Ok(ContractError::InvalidInput)));
+    assert_eq!(client.list_certificates_by_vintage(&2024, &0, &10), (Vec::new(&env), 0));
+    assert_eq!(client.try_migrate(&verifier_address, &records), Err(unauthorized()));
+    
+    // ✅ Lote completo migrado y esquema marcado como vigente
+    client.migrate(&admin, &records);
+    assert_eq!(client.get_certificate_data(&2), records.get_unchecked(1).1);
+    assert_eq!(client.list_certificates_by_vintage(&2024, &0, &10), (vec![&env, 1u32, 2u32], 2));
+    
+    // ✅ Al cerrar la migración el admin recibe los roles operativos; `revoker` se otorga aparte
+    client.finish_migration(&admin);
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+    assert!(client.has_role(&admin, &config_manager).is_some());
+    assert_eq!(client.has_role(&admin, &Symbol::new(&env, REVOKER_ROLE)), None);
+    assert_eq!(client.get_total_co2e(), 3_000);
+    client.unpause(&admin);
+}
+
+// ============================================================================
+// PAUSA DE EMERGENCIA
+// ============================================================================
+
+#[test]
+fn test_pause_blocks_certificate_flows() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    let (name, reason) = claim(&env);
+    
+    let verifier_address = Address::generate(&env);
+    let (admin, _) = register_verifier(&env, &client, &verifier_address);
+    let farmer_address = Address::generate(&env);
+    let buyer_address = Address::generate(&env);
+    register_parcel(&env, &client, 1, &farmer_address);
+    register_parcel(&env, &client, 2, &farmer_address);
+    client.mint_certificate(&1, &record(&env, &verifier_address, &farmer_address, 1, 1_000));
+    
+    let pauser = Address::generate(&env);
+    client.grant_role(&admin, &pauser, &Symbol::new(&env, PAUSER_ROLE));
+    
+    // ❌ Solo el pauser o el admin pueden pausar
+    assert!(client.try_pause(&verifier_address).is_err());
+    assert!(!client.paused());
+    
+    client.pause(&pauser);
+    assert!(client.paused());
+    assert!(client.try_pause(&pauser).is_err());
+    
+    // ❌ Acuñación (directa, por lote o por propuesta), transferencia, división,
+    // fusión y quema bloqueadas
+    let record = record(&env, &verifier_address, &farmer_address, 2, 1_000);
+    assert!(client.try_mint_certificate(&2, &record).is_err());
+    assert!(client.try_mint_certificates_batch(&vec![&env, (2u32, record.clone())]).is_err());
+    assert!(client.try_propose_certificate(&2, &record).is_err());
+    assert!(client.try_transfer_certificate(&1, &farmer_address, &buyer_address).is_err());
+    assert!(client.try_split_certificate(&1, &vec![&env, 400u128, 600u128]).is_err());
+    assert!(client.try_merge_certificates(&vec![&env, 1u32, 2u32]).is_err());
+    assert!(client.try_burn_certificate(&1, &farmer_address, &name, &reason).is_err());
+    
+    // ✅ Las consultas siguen disponibles
+    assert_eq!(client.get_certificate_owner(&1), farmer_address);
+    assert_eq!(client.get_total_certificates(), 1);
+    
+    // ✅ El admin también puede reanudar
+    client.unpause(&admin);
+    assert!(!client.paused());
+    client.transfer_certificate(&1, &farmer_address, &buyer_address);
+    assert!(client.propose_certificate(&2, &record));
+    assert_eq!(client.get_total_certificates(), 2);
+    assert_eq!(client.split_certificate(&1, &vec![&env, 400u128, 600u128]).len(), 2);
+}
+
+// ============================================================================
+// TRASPASO DE ADMINISTRACIÓN
+// ============================================================================
+
+#[test]
+fn test_two_step_admin_handover() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let multisig = Address::generate(&env);
+    let token_id = Address::generate(&env);
+    assert_eq!(client.try_propose_admin(&multisig, &200), Err(Ok(ContractError::NotAuthorized)));
+    client.initialize(&admin);
+    client.grant_role(&admin, &admin, &Symbol::new(&env, REVOKER_ROLE));
+    client.grant_role(&admin, &admin, &Symbol::new(&env, UPGRADER_ROLE));
+    
+    // ❌ Solo el admin propone, a otra dirección y con expiración futura
+    assert_eq!(client.try_accept_admin(), Err(Ok(ContractError::NotFound)));
+    env.set_auths(&[]);
+    assert!(client.try_propose_admin(&multisig, &200).is_err());
+    env.mock_all_auths();
+    assert_eq!(client.try_propose_admin(&admin, &200), Err(Ok(ContractError::InvalidInput)));
+    assert_eq!(client.try_propose_admin(&multisig, &99), Err(Ok(ContractError::InvalidInput)));
+    
+    // ✅ La propuesta puede cancelarse antes de aceptarse
+    client.propose_admin(&multisig, &200);
+    assert_eq!(client.get_pending_admin(), Some(multisig.clone()));
+    assert_eq!(client.get_admin(), Some(admin.clone()));
+    client.cancel_admin_transfer();
+    assert_eq!(client.get_pending_admin(), None);
+    assert_eq!(client.try_accept_admin(), Err(Ok(ContractError::NotFound)));
+    assert_eq!(client.try_cancel_admin_transfer(), Err(Ok(ContractError::NotFound)));
+    
+    // ❌ Una propuesta expirada deja de estar pendiente y no puede aceptarse
+    client.propose_admin(&multisig, &200);
+    env.ledger().with_mut(|li| li.sequence_number = 201);
+    assert_eq!(client.get_pending_admin(), None);
+    assert_eq!(client.try_accept_admin(), Err(Ok(ContractError::NotFound)));
+    
+    // ✅ Aceptada: los roles operativos pasan del admin anterior al nuevo
+    client.propose_admin(&multisig, &300);
+    client.accept_admin();
+    assert_eq!(client.get_admin(), Some(multisig.clone()));
+    assert_eq!(client.get_pending_admin(), None);
+    for role in [REGISTRY_MANAGER_ROLE, CONFIG_MANAGER_ROLE, REVOKER_ROLE, PAUSER_ROLE, UPGRADER_ROLE] {
+        let role = Symbol::new(&env, role);
+        assert_eq!(client.has_role(&admin, &role), None);
+        assert!(client.has_role(&multisig, &role).is_some());
+    }
+    assert_eq!(client.try_set_token_contract_id(&admin, &token_id), Err(unauthorized()));
+    client.set_token_contract_id(&multisig, &token_id);
+}
+
+#[test]
+fn test_admin_transfer_through_access_control() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let multisig = Address::generate(&env);
+    client.initialize(&admin);
+    client.grant_role(&admin, &admin, &Symbol::new(&env, UPGRADER_ROLE));
+    
+    // ✅ La interfaz de `stellar-access` comparte el traspaso pendiente
+    client.transfer_admin_role(&multisig, &1_000);
+    assert_eq!(client.get_pending_admin(), Some(multisig.clone()));
+    client.transfer_admin_role(&multisig, &0);
+    assert_eq!(client.get_pending_admin(), None);
+    assert!(client.try_accept_admin_transfer().is_err());
+    
+    client.propose_admin(&multisig, &1_000);
+    client.accept_admin_transfer();
+    assert_eq!(client.get_admin(), Some(multisig.clone()));
+    assert_eq!(client.get_pending_admin(), None);
+    assert_eq!(client.has_role(&admin, &Symbol::new(&env, UPGRADER_ROLE)), None);
+    assert!(client.has_role(&multisig, &Symbol::new(&env, UPGRADER_ROLE)).is_some());
+}
+
+#[test]
+fn test_renounce_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let multisig = Address::generate(&env);
+    client.initialize(&admin);
+    client.grant_role(&admin, &admin, &Symbol::new(&env, UPGRADER_ROLE));
+    client.propose_admin(&multisig, &1_000);
+    
+    // ❌ Sin la firma del admin no se renuncia
+    env.set_auths(&[]);
+    assert!(client.try_renounce_admin().is_err());
+    env.mock_all_auths();
+    client.renounce_admin();
+    
+    // ✅ Sin admin ni traspaso pendiente, y sin posibilidad de reinicializar
+    assert_eq!(client.get_admin(), None);
+    assert_eq!(client.get_pending_admin(), None);
+    assert_eq!(client.try_propose_admin(&multisig, &1_000), Err(Ok(ContractError::NotAuthorized)));
+    assert!(client.try_grant_role(&admin, &multisig, &Symbol::new(&env, PAUSER_ROLE)).is_err());
+    assert_eq!(client.try_initialize(&multisig), Err(Ok(ContractError::AlreadyExists)));
+    
+    // ✅ El código queda fijo: el admin renuncia también al rol `upgrader`
+    assert_eq!(client.has_role(&admin, &Symbol::new(&env, UPGRADER_ROLE)), None);
+    assert_eq!(client.try_upgrade(&admin, &BytesN::from_array(&env, &[1u8; 32])), Err(unauthorized()));
+    
+    // ✅ Sus demás roles operativos siguen vigentes hasta que los renuncie
+    client.set_token_contract_id(&admin, &multisig);
+    client.pause(&admin);
+    assert!(client.paused());
+}
+
+// ============================================================================
+// ROLES
+// ============================================================================
+
+#[test]
+fn test_roles_gate_operations() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonCertifier);
+    let client = CarbonCertifierClient::new(&env, &contract_id);
+    
+    let verifier_address = Address::generate(&env);
+    let (admin, _) = register_verifier(&env, &client, &verifier_address);
+    let farmer_address = Address::generate(&env);
+    let manager = Address::generate(&env);
+    let revoker = Address::generate(&env);
+    let verifier_role = Symbol::new(&env, VERIFIER_ROLE);
+    
+    // ✅ Registrar un verificador le otorga el rol `verifier`
+    assert!(client.has_role(&verifier_address, &verifier_role).is_some());
+    
+    // ❌ Sin el rol `registry_manager` no se administra el registro
+    let hash = BytesN::from_array(&env, &[3u8; 32]);
+    let result = client.try_register_parcel(&manager, &1, &farmer_address, &hash, &1_000);
+    assert_eq!(result, Err(unauthorized()));
+    
+    client.grant_role(&admin, &manager, &Symbol::new(&env, REGISTRY_MANAGER_ROLE));
+    client.register_parcel(&manager, &1, &farmer_address, &hash, &1_000);
+    client.register_parcel(&manager, &2, &farmer_address, &hash, &1_000);
+    
+    // ❌ Un verificador registrado sin el rol `verifier` no puede acuñar
+    client.mint_certificate(&1, &record(&env, &verifier_address, &farmer_address, 1, 1_000));
+    client.revoke_role(&admin, &verifier_address, &verifier_role);
+    let record = record(&env, &verifier_address, &farmer_address, 2, 1_000);
+    assert_eq!(client.try_mint_certificate(&2, &record), Err(Ok(ContractError::VerifierNotAuthorized)));
+    client.grant_role(&admin, &verifier_address, &verifier_role);
+    client.mint_certificate(&2, &record);
+    
+    // ❌ Solo el emisor o el rol `revoker` revocan
+    let reason = BytesN::from_array(&env, &[9u8; 32]);
+    let result = client.try_revoke_certificate(&revoker, &1, &reason);
+    assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+    client.grant_role(&admin, &revoker, &Symbol::new(&env, REVOKER_ROLE));
+    client.revoke_certificate(&revoker, &1, &reason);
+    assert!(client.is_certificate_revoked(&1));
+    
+    // ✅ Eliminar al verificador del registro le retira el rol
+    client.remove_verifier(&manager, &verifier_address);
+    assert_eq!(client.has_role(&verifier_address, &verifier_role), None);
+    
+    // ✅ El rol `pauser` admite varios titulares; cada uno se otorga y retira por separado
+    let pauser_role = Symbol::new(&env, PAUSER_ROLE);
+    client.grant_role(&admin, &manager, &pauser_role);
+    client.grant_role(&admin, &revoker, &pauser_role);
+    assert_eq!(client.get_role_member_count(&pauser_role), 3);
+    client.revoke_role(&admin, &manager, &pauser_role);
+    assert_eq!(client.has_role(&manager, &pauser_role), None);
+    client.pause(&revoker);
+    client.unpause(&revoker);
+    client.renounce_role(&revoker, &pauser_role);
+    assert_eq!(client.has_role(&revoker, &pauser_role), None);
+    assert!(client.has_role(&admin, &pauser_role).is_some());
+}
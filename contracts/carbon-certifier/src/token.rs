@@ -0,0 +1,21 @@
+//! Interfaz mínima del contrato CarbonToken (CXO) usada por CarbonCertifier
+//! 
+//! Genera `CarbonTokenClient` para realizar llamadas cross-contract tipadas
+//! en lugar de construir manualmente los argumentos como `Vec<Val>`.
+
+use soroban_sdk::{contractclient, Address, Env};
+
+#[contractclient(name = "CarbonTokenClient")]
+pub trait CarbonTokenInterface {
+    /// Acuña `amount` tokens CXO para `to`; `minter` debe tener el rol de minter
+    fn mint(env: Env, minter: Address, to: Address, amount: i128);
+
+    /// Quema `amount` tokens CXO del balance de `from`; requiere la firma de `from`
+    fn burn(env: Env, from: Address, amount: i128);
+
+    /// Recupera y quema `amount` tokens CXO de `from`; `minter` debe tener el rol de minter
+    fn clawback(env: Env, minter: Address, from: Address, amount: i128);
+
+    /// Consulta el balance de CXO de `id`
+    fn balance(env: Env, id: Address) -> i128;
+}
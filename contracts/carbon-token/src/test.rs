@@ -1,5 +1,8 @@
 use super::*;
-use soroban_sdk::{testutils::Address as _, Env, Address};
+use soroban_sdk::{
+    map, testutils::{storage::Persistent as _, Address as _, Events as _, Ledger as _, MuxedAddress as MuxedAddressTestUtils},
+    vec, BytesN, Env, Address, IntoVal, Map, MuxedAddress, String, Symbol, Val,
+};
 
 #[test]
 fn test_initialize() {
@@ -30,9 +33,10 @@ fn test_mint_success() {
     
     // Inicializar
     client.initialize(&admin);
+    client.grant_minter(&admin);
     
     // Acuñar 100 tokens para el usuario
-    client.mint(&user, &100);
+    client.mint(&admin, &user, &100);
     
     // Verificar el balance
     let balance = client.balance(&user);
@@ -53,14 +57,14 @@ fn test_mint_unauthorized() {
     
     // Inicializar con admin real
     client.initialize(&admin);
+    client.grant_minter(&admin);
     
     // mock_all_auths() simula que todos están autenticados,
-    // pero el contrato verifica internamente que el caller sea el admin.
-    // Como fake_admin != admin, debe fallar con NotInitialized o Unauthorized.
-    
-    // La validación de admin está cubierta por require_admin()
-    // que verifica que el admin guardado coincida con el caller.
-    // En un entorno real sin mock_all_auths, require_auth() fallaría primero.
+    // pero el contrato verifica internamente que `minter` tenga el rol.
+    // Como fake_admin no es minter, debe fallar con Unauthorized.
+    let result = client.try_mint(&fake_admin, &user, &100);
+    assert_eq!(result, Err(Ok(TokenError::Unauthorized)));
+    assert_eq!(client.balance(&user), 0);
 }
 
 #[test]
@@ -76,13 +80,14 @@ fn test_mint_invalid_amount() {
     
     // Inicializar
     client.initialize(&admin);
+    client.grant_minter(&admin);
     
     // Intentar acuñar 0 tokens
-    let result = client.try_mint(&user, &0);
+    let result = client.try_mint(&admin, &user, &0);
     assert!(result.is_err());
     
     // Intentar acuñar tokens negativos
-    let result = client.try_mint(&user, &-100);
+    let result = client.try_mint(&admin, &user, &-100);
     assert!(result.is_err());
 }
 
@@ -100,9 +105,10 @@ fn test_transfer_success() {
     
     // Inicializar
     client.initialize(&admin);
+    client.grant_minter(&admin);
     
     // Acuñar 100 tokens para Alice
-    client.mint(&alice, &100);
+    client.mint(&admin, &alice, &100);
     assert_eq!(client.balance(&alice), 100);
     assert_eq!(client.balance(&bob), 0);
     
@@ -128,9 +134,10 @@ fn test_transfer_insufficient_balance() {
     
     // Inicializar
     client.initialize(&admin);
+    client.grant_minter(&admin);
     
     // Acuñar solo 50 tokens para Alice
-    client.mint(&alice, &50);
+    client.mint(&admin, &alice, &50);
     
     // Alice intenta transferir 100 tokens (más de los que tiene)
     let result = client.try_transfer(&alice, &bob, &100);
@@ -153,9 +160,10 @@ fn test_transfer_unauthorized() {
     
     // Inicializar
     client.initialize(&admin);
+    client.grant_minter(&admin);
     
     // Acuñar tokens para Alice
-    client.mint(&alice, &100);
+    client.mint(&admin, &alice, &100);
     
     // mock_all_auths() está activo, simula que todos están autenticados.
     // En un entorno real sin mock, require_auth() rechazaría automáticamente
@@ -177,15 +185,16 @@ fn test_multiple_mints() {
     
     // Inicializar
     client.initialize(&admin);
+    client.grant_minter(&admin);
     
     // Acuñar múltiples veces
-    client.mint(&user, &100);
+    client.mint(&admin, &user, &100);
     assert_eq!(client.balance(&user), 100);
     
-    client.mint(&user, &50);
+    client.mint(&admin, &user, &50);
     assert_eq!(client.balance(&user), 150);
     
-    client.mint(&user, &25);
+    client.mint(&admin, &user, &25);
     assert_eq!(client.balance(&user), 175);
 }
 
@@ -221,9 +230,10 @@ fn test_transfer_multiple_users() {
     
     // Inicializar
     client.initialize(&admin);
+    client.grant_minter(&admin);
     
     // Acuñar tokens para Alice
-    client.mint(&alice, &1000);
+    client.mint(&admin, &alice, &1000);
     
     // Alice transfiere a Bob
     client.transfer(&alice, &bob, &300);
@@ -254,12 +264,13 @@ fn test_approve_success() {
     
     // Inicializar
     client.initialize(&admin);
+    client.grant_minter(&admin);
     
     // Acuñar tokens para Alice
-    client.mint(&alice, &1000);
+    client.mint(&admin, &alice, &1000);
     
     // Alice aprueba a Bob para gastar 300 tokens
-    client.approve(&alice, &bob, &300);
+    client.approve(&alice, &bob, &300, &1000);
     
     // Verificar que la asignación se registró correctamente
     assert_eq!(client.allowance(&alice, &bob), 300);
@@ -299,12 +310,13 @@ fn test_transfer_from_success() {
     
     // Inicializar
     client.initialize(&admin);
+    client.grant_minter(&admin);
     
     // Acuñar tokens para Alice
-    client.mint(&alice, &1000);
+    client.mint(&admin, &alice, &1000);
     
     // Alice aprueba a Bob para gastar 300 tokens
-    client.approve(&alice, &bob, &300);
+    client.approve(&alice, &bob, &300, &1000);
     
     // Bob transfiere 200 tokens de Alice a Charlie
     client.transfer_from(&bob, &alice, &charlie, &200);
@@ -332,12 +344,13 @@ fn test_transfer_from_insufficient_balance() {
     
     // Inicializar
     client.initialize(&admin);
+    client.grant_minter(&admin);
     
     // Acuñar solo 100 tokens para Alice
-    client.mint(&alice, &100);
+    client.mint(&admin, &alice, &100);
     
     // Alice aprueba a Bob para gastar 200 tokens (más de lo que tiene)
-    client.approve(&alice, &bob, &200);
+    client.approve(&alice, &bob, &200, &1000);
     
     // Bob intenta transferir 200 tokens de Alice a Charlie
     let result = client.try_transfer_from(&bob, &alice, &charlie, &200);
@@ -361,12 +374,13 @@ fn test_transfer_from_insufficient_allowance() {
     
     // Inicializar
     client.initialize(&admin);
+    client.grant_minter(&admin);
     
     // Acuñar tokens para Alice
-    client.mint(&alice, &1000);
+    client.mint(&admin, &alice, &1000);
     
     // Alice aprueba a Bob solo para 100 tokens
-    client.approve(&alice, &bob, &100);
+    client.approve(&alice, &bob, &100, &1000);
     
     // Bob intenta transferir 200 tokens (más de lo aprobado)
     let result = client.try_transfer_from(&bob, &alice, &charlie, &200);
@@ -394,12 +408,13 @@ fn test_approve_zero_allows_transfer() {
     
     // Inicializar
     client.initialize(&admin);
+    client.grant_minter(&admin);
     
     // Acuñar tokens para Alice
-    client.mint(&alice, &1000);
+    client.mint(&admin, &alice, &1000);
     
     // Alice aprueba a Bob para 0 tokens
-    client.approve(&alice, &bob, &0);
+    client.approve(&alice, &bob, &0, &1000);
     
     // Bob intenta transferir (debe fallar por allowance insuficiente)
     let result = client.try_transfer_from(&bob, &alice, &charlie, &100);
@@ -421,12 +436,13 @@ fn test_approve_update_allows_partial_spend() {
     
     // Inicializar
     client.initialize(&admin);
+    client.grant_minter(&admin);
     
     // Acuñar tokens para Alice
-    client.mint(&alice, &1000);
+    client.mint(&admin, &alice, &1000);
     
     // Alice aprueba a Bob para 500 tokens
-    client.approve(&alice, &bob, &500);
+    client.approve(&alice, &bob, &500, &1000);
     assert_eq!(client.allowance(&alice, &bob), 500);
     
     // Bob transfiere 300 tokens
@@ -434,7 +450,7 @@ fn test_approve_update_allows_partial_spend() {
     assert_eq!(client.allowance(&alice, &bob), 200);  // 500 - 300
     
     // Alice actualiza la aprobación a 1000
-    client.approve(&alice, &bob, &1000);
+    client.approve(&alice, &bob, &1000, &1000);
     assert_eq!(client.allowance(&alice, &bob), 1000);
     
     // Bob puede transferir hasta 1000 (la nueva asignación)
@@ -443,3 +459,748 @@ fn test_approve_update_allows_partial_spend() {
 }
 
 
+
+// ============================================================================
+// Tests para burn
+// ============================================================================
+
+#[test]
+fn test_burn_success() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonToken);
+    let client = CarbonTokenClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+    
+    client.initialize(&admin);
+    client.grant_minter(&admin);
+    client.mint(&admin, &alice, &1000);
+    
+    // Alice quema 400 tokens
+    client.burn(&alice, &400);
+    assert_eq!(client.balance(&alice), 600);
+}
+
+#[test]
+fn test_burn_insufficient_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonToken);
+    let client = CarbonTokenClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+    
+    client.initialize(&admin);
+    client.grant_minter(&admin);
+    client.mint(&admin, &alice, &100);
+    
+    // No se puede quemar más de lo que se tiene
+    let result = client.try_burn(&alice, &101);
+    assert_eq!(result, Err(Ok(TokenError::InsufficientBalance.into())));
+    assert_eq!(client.balance(&alice), 100);
+    
+    // Cantidades no positivas son inválidas
+    let result = client.try_burn(&alice, &0);
+    assert_eq!(result, Err(Ok(TokenError::InvalidAmount.into())));
+}
+
+// ============================================================================
+// Tests para la oferta total
+// ============================================================================
+
+#[test]
+fn test_supply_initial_zero() {
+    let env = Env::default();
+    
+    let contract_id = env.register_contract(None, CarbonToken);
+    let client = CarbonTokenClient::new(&env, &contract_id);
+    
+    client.initialize(&Address::generate(&env));
+    
+    assert_eq!(client.total_supply(), 0);
+    assert_eq!(client.total_minted(), 0);
+    assert_eq!(client.total_burned(), 0);
+}
+
+#[test]
+fn test_supply_tracks_mint_and_burn() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonToken);
+    let client = CarbonTokenClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    
+    client.initialize(&admin);
+    client.grant_minter(&admin);
+    
+    // Acuñar para dos usuarios
+    client.mint(&admin, &alice, &1000);
+    client.mint(&admin, &bob, &500);
+    assert_eq!(client.total_minted(), 1500);
+    assert_eq!(client.total_supply(), 1500);
+    
+    // Las transferencias no cambian la oferta
+    client.transfer(&alice, &bob, &200);
+    assert_eq!(client.total_supply(), 1500);
+    
+    // burn y burn_from reducen la oferta
+    client.burn(&alice, &300);
+    client.approve(&bob, &alice, &100, &1000);
+    client.burn_from(&alice, &bob, &100);
+    assert_eq!(client.total_burned(), 400);
+    assert_eq!(client.total_minted(), 1500);
+    assert_eq!(client.total_supply(), 1100);
+    assert_eq!(client.total_supply(), client.balance(&alice) + client.balance(&bob));
+    
+    // Una quema fallida no altera los contadores
+    let result = client.try_burn(&alice, &10_000);
+    assert!(result.is_err());
+    assert_eq!(client.total_burned(), 400);
+}
+
+// ============================================================================
+// Tests para el rol de minter
+// ============================================================================
+
+#[test]
+fn test_grant_minter_success() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonToken);
+    let client = CarbonTokenClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let certifier = Address::generate(&env);
+    let user = Address::generate(&env);
+    
+    client.initialize(&admin);
+    assert_eq!(client.minters().len(), 0);
+    
+    // El admin otorga el rol de minter al certificador
+    client.grant_minter(&certifier);
+    assert!(client.is_minter(&certifier));
+    assert!(!client.is_minter(&admin));
+    assert_eq!(client.minters(), soroban_sdk::vec![&env, certifier.clone()]);
+    
+    // El certificador puede acuñar; el admin (sin rol) no
+    client.mint(&certifier, &user, &100);
+    assert_eq!(client.balance(&user), 100);
+    let result = client.try_mint(&admin, &user, &100);
+    assert_eq!(result, Err(Ok(TokenError::Unauthorized)));
+}
+
+#[test]
+fn test_grant_minter_already_exists() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonToken);
+    let client = CarbonTokenClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let certifier = Address::generate(&env);
+    
+    client.initialize(&admin);
+    client.grant_minter(&certifier);
+    
+    let result = client.try_grant_minter(&certifier);
+    assert_eq!(result, Err(Ok(TokenError::MinterAlreadyExists)));
+    assert_eq!(client.minters().len(), 1);
+}
+
+#[test]
+fn test_grant_minter_requires_admin() {
+    let env = Env::default();
+    
+    let contract_id = env.register_contract(None, CarbonToken);
+    let client = CarbonTokenClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let certifier = Address::generate(&env);
+    
+    client.initialize(&admin);
+    
+    // Sin la firma del admin no se pueden otorgar roles
+    let result = client.try_grant_minter(&certifier);
+    assert!(result.is_err());
+    assert!(!client.is_minter(&certifier));
+}
+
+#[test]
+fn test_revoke_minter() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonToken);
+    let client = CarbonTokenClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let certifier_a = Address::generate(&env);
+    let certifier_b = Address::generate(&env);
+    let user = Address::generate(&env);
+    
+    client.initialize(&admin);
+    client.grant_minter(&certifier_a);
+    client.grant_minter(&certifier_b);
+    assert_eq!(client.minters().len(), 2);
+    
+    // Revocar a certifier_a
+    client.revoke_minter(&certifier_a);
+    assert!(!client.is_minter(&certifier_a));
+    assert!(client.is_minter(&certifier_b));
+    assert_eq!(client.minters(), soroban_sdk::vec![&env, certifier_b.clone()]);
+    
+    let result = client.try_mint(&certifier_a, &user, &100);
+    assert_eq!(result, Err(Ok(TokenError::Unauthorized)));
+    client.mint(&certifier_b, &user, &100);
+    assert_eq!(client.balance(&user), 100);
+    
+    // Revocar un minter inexistente falla
+    let result = client.try_revoke_minter(&certifier_a);
+    assert_eq!(result, Err(Ok(TokenError::MinterNotFound)));
+}
+
+#[test]
+fn test_clawback_by_minter() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonToken);
+    let client = CarbonTokenClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let certifier = Address::generate(&env);
+    let user = Address::generate(&env);
+    
+    client.initialize(&admin);
+    client.grant_minter(&certifier);
+    client.mint(&certifier, &user, &1000);
+    
+    // ✅ El minter recupera tokens sin la firma del titular
+    client.clawback(&certifier, &user, &400);
+    assert_eq!(client.balance(&user), 600);
+    assert_eq!(client.total_burned(), 400);
+    assert_eq!(client.total_supply(), 600);
+    
+    let result = client.try_clawback(&certifier, &user, &601);
+    assert_eq!(result, Err(Ok(TokenError::InsufficientBalance)));
+    let result = client.try_clawback(&certifier, &user, &0);
+    assert_eq!(result, Err(Ok(TokenError::InvalidAmount)));
+}
+
+#[test]
+fn test_clawback_requires_minter() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonToken);
+    let client = CarbonTokenClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let attacker = Address::generate(&env);
+    
+    client.initialize(&admin);
+    client.grant_minter(&admin);
+    client.mint(&admin, &user, &1000);
+    
+    let result = client.try_clawback(&attacker, &user, &1000);
+    assert_eq!(result, Err(Ok(TokenError::Unauthorized)));
+    assert_eq!(client.balance(&user), 1000);
+}
+
+// ============================================================================
+// Tests para la interfaz SEP-41
+// ============================================================================
+
+#[test]
+fn test_metadata() {
+    let env = Env::default();
+    
+    let contract_id = env.register_contract(None, CarbonToken);
+    let client = CarbonTokenClient::new(&env, &contract_id);
+    
+    client.initialize(&Address::generate(&env));
+    
+    assert_eq!(client.name(), String::from_str(&env, "CARBONXO"));
+    assert_eq!(client.symbol(), String::from_str(&env, "CXO"));
+    assert_eq!(client.decimals(), 7);
+}
+
+#[test]
+fn test_allowance_expires() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonToken);
+    let client = CarbonTokenClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let charlie = Address::generate(&env);
+    
+    client.initialize(&admin);
+    client.grant_minter(&admin);
+    client.mint(&admin, &alice, &1000);
+    
+    // Alice aprueba a Bob hasta el ledger 100
+    client.approve(&alice, &bob, &300, &100);
+    assert_eq!(client.allowance(&alice, &bob), 300);
+    
+    // En el ledger 100 todavía es válida
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+    assert_eq!(client.allowance(&alice, &bob), 300);
+    
+    // Después del ledger 100 se considera 0 y no se puede gastar
+    env.ledger().with_mut(|li| li.sequence_number = 101);
+    assert_eq!(client.allowance(&alice, &bob), 0);
+    let result = client.try_transfer_from(&bob, &alice, &charlie, &100);
+    assert_eq!(result, Err(Ok(TokenError::InsufficientAllowance.into())));
+}
+
+#[test]
+fn test_approve_expired_ledger_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonToken);
+    let client = CarbonTokenClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    
+    client.initialize(&admin);
+    env.ledger().with_mut(|li| li.sequence_number = 50);
+    
+    // Una asignación positiva no puede expirar en el pasado
+    let result = client.try_approve(&alice, &bob, &300, &49);
+    assert_eq!(result, Err(Ok(TokenError::InvalidExpirationLedger.into())));
+    
+    // Pero sí se puede revocar (amount = 0) con cualquier expiración
+    client.approve(&alice, &bob, &0, &0);
+    assert_eq!(client.allowance(&alice, &bob), 0);
+}
+
+#[test]
+fn test_burn_from_success() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonToken);
+    let client = CarbonTokenClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    
+    client.initialize(&admin);
+    client.grant_minter(&admin);
+    client.mint(&admin, &alice, &1000);
+    
+    // Bob quema 200 tokens de Alice usando su asignación
+    client.approve(&alice, &bob, &300, &1000);
+    client.burn_from(&bob, &alice, &200);
+    assert_eq!(client.balance(&alice), 800);
+    assert_eq!(client.allowance(&alice, &bob), 100);
+    
+    // No puede exceder la asignación restante
+    let result = client.try_burn_from(&bob, &alice, &200);
+    assert_eq!(result, Err(Ok(TokenError::InsufficientAllowance.into())));
+    assert_eq!(client.balance(&alice), 800);
+}
+
+#[test]
+fn test_sep41_event_shapes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonToken);
+    let client = CarbonTokenClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    
+    client.initialize(&admin);
+    client.grant_minter(&admin);
+    
+    // mint: topics ["mint", to], data amount
+    client.mint(&admin, &alice, &1000);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_id.clone(),
+                (Symbol::new(&env, "mint"), alice.clone()).into_val(&env),
+                1000_i128.into_val(&env),
+            ),
+        ]
+    );
+    
+    // transfer: topics ["transfer", from, to], data amount
+    client.transfer(&alice, &bob, &100);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_id.clone(),
+                (Symbol::new(&env, "transfer"), alice.clone(), bob.clone()).into_val(&env),
+                100_i128.into_val(&env),
+            ),
+        ]
+    );
+    
+    // transfer a cuenta multiplexada: data { to_muxed_id, amount }
+    let carol_muxed = <MuxedAddress as MuxedAddressTestUtils>::generate(&env);
+    let carol = carol_muxed.address();
+    client.transfer(&alice, &carol_muxed, &100);
+    let transfer_data: Map<Symbol, Val> = map![
+        &env,
+        (Symbol::new(&env, "amount"), 100_i128.into_val(&env)),
+        (Symbol::new(&env, "to_muxed_id"), carol_muxed.id().unwrap().into_val(&env)),
+    ];
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_id.clone(),
+                (Symbol::new(&env, "transfer"), alice.clone(), carol.clone()).into_val(&env),
+                transfer_data.into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(client.balance(&carol), 100);
+    
+    // approve: topics ["approve", from, spender], data [amount, expiration_ledger]
+    client.approve(&alice, &bob, &50, &1000);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_id.clone(),
+                (Symbol::new(&env, "approve"), alice.clone(), bob.clone()).into_val(&env),
+                (50_i128, 1000_u32).into_val(&env),
+            ),
+        ]
+    );
+    
+    // burn: topics ["burn", from], data amount
+    client.burn(&alice, &10);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_id.clone(),
+                (Symbol::new(&env, "burn"), alice.clone()).into_val(&env),
+                10_i128.into_val(&env),
+            ),
+        ]
+    );
+}
+
+// ============================================================================
+// Tests para el TTL de los balances
+// ============================================================================
+
+fn balance_ttl(env: &Env, contract_id: &Address, account: &Address) -> u32 {
+    env.as_contract(contract_id, || {
+        env.storage().persistent().get_ttl(&DataKey::Balance(account.clone()))
+    })
+}
+
+#[test]
+fn test_balance_ttl_extended_and_bumped() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonToken);
+    let client = CarbonTokenClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.initialize(&admin);
+    client.grant_minter(&admin);
+    
+    // ✅ Acuñar deja el balance con el TTL por defecto
+    client.mint(&admin, &alice, &1_000);
+    let extend_to = client.get_ttl_config().extend_to_ledgers;
+    assert_eq!(balance_ttl(&env, &contract_id, &alice), extend_to);
+    
+    // Sin tocarlo, el TTL cae por debajo del umbral
+    env.ledger().with_mut(|li| li.sequence_number += 1_600_000);
+    assert_eq!(balance_ttl(&env, &contract_id, &alice), extend_to - 1_600_000);
+    
+    // ✅ Consultar el balance lo renueva
+    assert_eq!(client.balance(&alice), 1_000);
+    assert_eq!(balance_ttl(&env, &contract_id, &alice), extend_to);
+    
+    // ✅ Cualquiera puede renovar un balance; una cuenta sin balance no falla
+    env.ledger().with_mut(|li| li.sequence_number += 1_600_000);
+    client.bump_account(&alice);
+    assert_eq!(balance_ttl(&env, &contract_id, &alice), extend_to);
+    client.bump_account(&bob);
+    assert_eq!(client.balance(&bob), 0);
+}
+
+#[test]
+fn test_set_ttl_config() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonToken);
+    let client = CarbonTokenClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+    client.initialize(&admin);
+    client.grant_minter(&admin);
+    
+    // ✅ VALIDACIÓN: La extensión debe superar al umbral y no exceder el máximo de la red
+    let invalid = TtlConfig { threshold_ledgers: 1_000, extend_to_ledgers: 500 };
+    assert_eq!(client.try_set_ttl_config(&invalid), Err(Ok(TokenError::InvalidTtlConfig)));
+    let too_long = TtlConfig { threshold_ledgers: 1_000, extend_to_ledgers: u32::MAX };
+    assert_eq!(client.try_set_ttl_config(&too_long), Err(Ok(TokenError::InvalidTtlConfig)));
+    
+    let config = TtlConfig { threshold_ledgers: 2_500_000, extend_to_ledgers: 3_000_000 };
+    client.set_ttl_config(&config);
+    assert_eq!(client.get_ttl_config(), config);
+    
+    client.mint(&admin, &alice, &1_000);
+    assert_eq!(balance_ttl(&env, &contract_id, &alice), 3_000_000);
+}
+
+// ============================================================================
+// Tests para la actualización del contrato
+// ============================================================================
+
+#[test]
+fn test_upgrade_requires_admin() {
+    let env = Env::default();
+    
+    let contract_id = env.register_contract(None, CarbonToken);
+    let client = CarbonTokenClient::new(&env, &contract_id);
+    let wasm_hash = BytesN::from_array(&env, &[1u8; 32]);
+    
+    // Sin admin no hay quién autorice la actualización
+    assert_eq!(client.try_upgrade(&wasm_hash), Err(Ok(TokenError::NotInitialized)));
+    
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    assert_eq!(client.schema_version(), SCHEMA_VERSION);
+    
+    // ❌ Sin la firma del admin la actualización falla
+    assert!(client.try_upgrade(&wasm_hash).is_err());
+}
+
+#[test]
+fn test_migrate_whole_unit_balances() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonToken);
+    let client = CarbonTokenClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.initialize(&admin);
+    
+    // Despliegue original (sin marcador de esquema) con balances en CXO enteros
+    env.as_contract(&contract_id, || {
+        env.storage().instance().remove(&DataKey::SchemaVersion);
+        env.storage().persistent().set(&DataKey::Balance(alice.clone()), &3i128);
+        env.storage().persistent().set(&DataKey::Balance(bob.clone()), &2i128);
+        env.storage().instance().set(&DataKey::TotalMinted, &6i128);
+        env.storage().instance().set(&DataKey::TotalBurned, &1i128);
+    });
+    assert_eq!(client.schema_version(), 1);
+    
+    // ❌ Solo con el contrato pausado y en lotes no vacíos
+    assert!(client.try_migrate_balances(&vec![&env, alice.clone()]).is_err());
+    client.pause(&admin);
+    assert_eq!(client.try_migrate_balances(&soroban_sdk::Vec::new(&env)), Err(Ok(TokenError::InvalidMigrationBatch)));
+    
+    // ✅ Cada balance se reescala una sola vez
+    client.migrate_balances(&vec![&env, alice.clone()]);
+    assert_eq!(client.balance(&alice), 3 * 10_000_000);
+    assert_eq!(client.try_migrate_balances(&vec![&env, bob.clone(), alice.clone()]), Err(Ok(TokenError::AlreadyMigrated)));
+    assert_eq!(client.balance(&bob), 2);
+    client.migrate_balances(&vec![&env, bob.clone()]);
+    assert_eq!(client.balance(&bob), 2 * 10_000_000);
+    
+    // ✅ Al cerrar la migración los totales quedan en unidades mínimas
+    client.finish_migration();
+    assert_eq!(client.schema_version(), SCHEMA_VERSION);
+    assert_eq!(client.total_supply(), 5 * 10_000_000);
+    assert_eq!(client.try_finish_migration(), Err(Ok(TokenError::AlreadyMigrated)));
+    assert_eq!(client.try_migrate_balances(&vec![&env, alice.clone()]), Err(Ok(TokenError::AlreadyMigrated)));
+    
+    client.unpause(&admin);
+    client.burn(&alice, &(3 * 10_000_000));
+    assert_eq!(client.balance(&alice), 0);
+}
+
+#[test]
+fn test_legacy_allowances_require_new_approval() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonToken);
+    let client = CarbonTokenClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.initialize(&admin);
+    
+    // Despliegue original con una asignación persistente de 2 CXO enteros
+    env.as_contract(&contract_id, || {
+        env.storage().instance().remove(&DataKey::SchemaVersion);
+        env.storage().persistent().set(&DataKey::Balance(alice.clone()), &3i128);
+        env.storage().persistent().set(&DataKey::Allowance(alice.clone(), bob.clone()), &2i128);
+        env.storage().instance().set(&DataKey::TotalMinted, &3i128);
+    });
+    client.pause(&admin);
+    client.migrate_balances(&vec![&env, alice.clone()]);
+    client.finish_migration();
+    client.unpause(&admin);
+    
+    // ❌ La asignación del esquema `1` no se migra: cuenta como 0
+    assert_eq!(client.allowance(&alice, &bob), 0);
+    assert!(client.try_transfer_from(&bob, &alice, &bob, &10_000_000).is_err());
+    
+    // ✅ El dueño vuelve a aprobar en unidades mínimas y con expiración
+    client.approve(&alice, &bob, &(2 * 10_000_000), &1000);
+    client.transfer_from(&bob, &alice, &bob, &(2 * 10_000_000));
+    assert_eq!(client.balance(&bob), 2 * 10_000_000);
+    assert_eq!(client.allowance(&alice, &bob), 0);
+}
+
+// ============================================================================
+// Tests para la pausa de emergencia
+// ============================================================================
+
+#[test]
+fn test_pause_blocks_mint_and_transfers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonToken);
+    let client = CarbonTokenClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let pauser = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.initialize(&admin);
+    client.grant_minter(&admin);
+    client.mint(&admin, &alice, &1_000);
+    client.approve(&alice, &bob, &500, &1000);
+    
+    client.set_pauser(&pauser);
+    assert_eq!(client.pauser(), Some(pauser.clone()));
+    
+    // ❌ Solo el pauser o el admin pueden pausar
+    assert!(client.try_pause(&bob).is_err());
+    client.pause(&pauser);
+    assert!(client.paused());
+    
+    // ❌ Acuñación y movimientos bloqueados
+    assert!(client.try_mint(&admin, &alice, &100).is_err());
+    assert!(client.try_transfer(&alice, &bob, &100).is_err());
+    assert!(client.try_transfer_from(&bob, &alice, &bob, &100).is_err());
+    
+    // ✅ Las consultas siguen disponibles
+    assert_eq!(client.balance(&alice), 1_000);
+    assert_eq!(client.allowance(&alice, &bob), 500);
+    assert_eq!(client.total_supply(), 1_000);
+    
+    client.unpause(&pauser);
+    assert!(!client.paused());
+    client.transfer(&alice, &bob, &100);
+    assert_eq!(client.balance(&bob), 100);
+}
+
+// ============================================================================
+// Tests para el traspaso de administración
+// ============================================================================
+
+#[test]
+fn test_two_step_admin_handover() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+    
+    let contract_id = env.register_contract(None, CarbonToken);
+    let client = CarbonTokenClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let multisig = Address::generate(&env);
+    let certifier = Address::generate(&env);
+    client.initialize(&admin);
+    
+    assert_eq!(client.try_accept_admin(), Err(Ok(TokenError::AdminTransferNotFound)));
+    assert_eq!(client.try_propose_admin(&multisig, &99), Err(Ok(TokenError::InvalidExpirationLedger)));
+    assert_eq!(client.try_propose_admin(&admin, &200), Err(Ok(TokenError::InvalidNewAdmin)));
+    assert_eq!(client.pending_admin(), None);
+    
+    // ✅ La propuesta puede cancelarse antes de aceptarse
+    client.propose_admin(&multisig, &200);
+    assert_eq!(client.pending_admin(), Some(PendingAdmin { new_admin: multisig.clone(), expiration_ledger: 200 }));
+    client.cancel_admin_transfer();
+    assert_eq!(client.try_accept_admin(), Err(Ok(TokenError::AdminTransferNotFound)));
+    
+    // ❌ Una propuesta expirada no puede aceptarse
+    client.propose_admin(&multisig, &200);
+    env.ledger().with_mut(|li| li.sequence_number = 201);
+    assert_eq!(client.try_accept_admin(), Err(Ok(TokenError::AdminTransferExpired)));
+    
+    // ✅ Aceptada: el nuevo admin firma la administración de minters
+    client.propose_admin(&multisig, &300);
+    client.accept_admin();
+    assert_eq!(client.admin(), Some(multisig.clone()));
+    assert_eq!(client.pending_admin(), None);
+    client.grant_minter(&certifier);
+    assert_eq!(env.auths()[0].0, multisig);
+}
+
+#[test]
+fn test_renounce_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, CarbonToken);
+    let client = CarbonTokenClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let minter = Address::generate(&env);
+    client.initialize(&admin);
+    client.grant_minter(&minter);
+    
+    client.renounce_admin();
+    
+    // ✅ Sin admin no se administran roles ni se puede reinicializar; los minters siguen operando
+    assert_eq!(client.admin(), None);
+    assert_eq!(client.try_grant_minter(&admin), Err(Ok(TokenError::NotInitialized)));
+    assert_eq!(client.try_initialize(&admin), Err(Ok(TokenError::NotInitialized)));
+    client.mint(&minter, &admin, &100);
+    assert_eq!(client.balance(&admin), 100);
+}
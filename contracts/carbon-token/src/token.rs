@@ -1,12 +1,36 @@
 /// CarbonToken - Contrato de Token Fungible CARBONXO (CXO)
 /// 
 /// Este contrato representa la unidad monetaria de tokenización de carbono:
-/// 1 CXO = 1 Tonelada de CO2e
+/// 1 CXO = 1 Tonelada de CO2e, con 7 decimales (1 kg de CO2e = 0.001 CXO)
 /// 
-/// Implementa la interfaz de token fungible de Soroban para permitir
-/// acuñación, transferencias y consultas de balance de tokens CARBONXO.
+/// Implementa la interfaz estándar de token fungible SEP-41 (`TokenInterface`)
+/// para que wallets y DEX reconozcan CXO, además de la acuñación por minters.
 
-use soroban_sdk::{contract, contractimpl, contracttype, contracterror, contractevent, Address, Env};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, contracterror, contractevent, panic_with_error,
+    token::TokenInterface, Address, BytesN, Env, MuxedAddress, String, Vec,
+};
+use stellar_contract_utils::pausable::{self as pausable, Pausable};
+
+/// Nombre del token
+const TOKEN_NAME: &str = "CARBONXO";
+/// Símbolo del token
+const TOKEN_SYMBOL: &str = "CXO";
+/// Decimales del token (10^7 unidades = 1 CXO = 1 tonelada de CO2e)
+const TOKEN_DECIMALS: u32 = 7;
+/// TTL restante por debajo del cual se renuevan los balances (~30 días)
+const DEFAULT_TTL_THRESHOLD_LEDGERS: u32 = 518_400;
+/// TTL al que se extienden los balances renovados (~120 días)
+const DEFAULT_TTL_EXTEND_TO_LEDGERS: u32 = 2_073_600;
+/// Unidades mínimas por CXO entero (10^7)
+const UNITS_PER_CXO: i128 = 10_000_000;
+/// Máximo de cuentas por llamada a `migrate_balances`
+const MAX_MIGRATION_BATCH: u32 = 50;
+/// Versión del esquema de almacenamiento que implementa este código
+/// 
+/// * `1` - Balances en CXO enteros, sin decimales
+/// * `2` - Balances en unidades mínimas de 7 decimales; asignaciones con expiración en Temporary Storage
+pub const SCHEMA_VERSION: u32 = 2;
 
 #[contract]
 pub struct CarbonToken;
@@ -17,7 +41,7 @@ pub struct CarbonToken;
 pub enum TokenError {
     /// El contrato no ha sido inicializado
     NotInitialized = 1,
-    /// Intentó hacer una operación no autorizada (solo admin puede acuñar)
+    /// Intentó hacer una operación no autorizada (solo minters pueden acuñar)
     Unauthorized = 2,
     /// Balance insuficiente para la transferencia
     InsufficientBalance = 3,
@@ -25,40 +49,217 @@ pub enum TokenError {
     InvalidAmount = 4,
     /// Asignación insuficiente para transferir en nombre del dueño
     InsufficientAllowance = 5,
+    /// La dirección ya tiene el rol de minter
+    MinterAlreadyExists = 6,
+    /// La dirección no tiene el rol de minter
+    MinterNotFound = 7,
+    /// Ledger de expiración de la asignación anterior al ledger actual
+    InvalidExpirationLedger = 8,
+    /// Configuración de TTL inválida (extensión no mayor al umbral o sobre el máximo de la red)
+    InvalidTtlConfig = 9,
+    /// No hay un traspaso de administración pendiente
+    AdminTransferNotFound = 10,
+    /// El traspaso de administración propuesto ya expiró
+    AdminTransferExpired = 11,
+    /// El esquema o el balance de la cuenta ya está en unidades de 7 decimales
+    AlreadyMigrated = 12,
+    /// Lote de migración vacío o con más de 50 cuentas
+    InvalidMigrationBatch = 13,
+    /// El administrador propuesto ya es el administrador actual
+    InvalidNewAdmin = 14,
 }
 
 /// Eventos del contrato
-#[contractevent]
+/// 
+/// Topics `["mint", to]`, data `amount`
+#[contractevent(topics = ["mint"], data_format = "single-value")]
 #[derive(Clone)]
 pub struct MintEvent {
     /// Dirección del receptor
+    #[topic]
     pub to: Address,
     /// Cantidad acuñada
     pub amount: i128,
 }
 
-/// Evento de transferencia de tokens
-#[contractevent]
+/// Evento de transferencia de tokens a una dirección sin ID multiplexado
+/// 
+/// Topics `["transfer", from, to]`, data `amount`
+#[contractevent(topics = ["transfer"], data_format = "single-value")]
 #[derive(Clone)]
 pub struct TransferEvent {
     /// Dirección del remitente
+    #[topic]
     pub from: Address,
     /// Dirección del receptor
+    #[topic]
     pub to: Address,
     /// Cantidad transferida
     pub amount: i128,
 }
 
+/// Evento de transferencia de tokens a una cuenta multiplexada
+/// 
+/// Topics `["transfer", from, to]`, data `{ to_muxed_id, amount }`
+#[contractevent(topics = ["transfer"])]
+#[derive(Clone)]
+pub struct TransferMuxedEvent {
+    /// Dirección del remitente
+    #[topic]
+    pub from: Address,
+    /// Dirección del receptor
+    #[topic]
+    pub to: Address,
+    /// ID de la cuenta multiplexada del receptor
+    pub to_muxed_id: u64,
+    /// Cantidad transferida
+    pub amount: i128,
+}
+
 /// Evento de aprobación de gasto delegado
-#[contractevent]
+/// 
+/// Topics `["approve", owner, spender]`, data `[amount, expiration_ledger]`
+#[contractevent(topics = ["approve"], data_format = "vec")]
 #[derive(Clone)]
 pub struct ApprovalEvent {
     /// Dirección del propietario
+    #[topic]
     pub owner: Address,
     /// Dirección autorizada para gastar
+    #[topic]
     pub spender: Address,
     /// Cantidad autorizada
     pub amount: i128,
+    /// Ledger a partir del cual la asignación expira
+    pub expiration_ledger: u32,
+}
+
+/// Evento de quema de tokens
+/// 
+/// Topics `["burn", from]`, data `amount`
+#[contractevent(topics = ["burn"], data_format = "single-value")]
+#[derive(Clone)]
+pub struct BurnEvent {
+    /// Dirección cuyos tokens se queman
+    #[topic]
+    pub from: Address,
+    /// Cantidad quemada
+    pub amount: i128,
+}
+
+/// Evento de recuperación (clawback) de tokens por un minter
+/// 
+/// Topics `["clawback", from]`, data `amount`
+#[contractevent(topics = ["clawback"], data_format = "single-value")]
+#[derive(Clone)]
+pub struct ClawbackEvent {
+    /// Dirección cuyos tokens se recuperan
+    #[topic]
+    pub from: Address,
+    /// Cantidad recuperada y quemada
+    pub amount: i128,
+}
+
+/// Evento de asignación del rol de minter
+#[contractevent]
+#[derive(Clone)]
+pub struct MinterGrantedEvent {
+    /// Dirección que recibe el rol
+    pub minter: Address,
+}
+
+/// Evento de revocación del rol de minter
+#[contractevent]
+#[derive(Clone)]
+pub struct MinterRevokedEvent {
+    /// Dirección que pierde el rol
+    pub minter: Address,
+}
+
+/// Evento de actualización de la configuración de TTL
+#[contractevent]
+#[derive(Clone)]
+pub struct TtlConfigUpdatedEvent {
+    /// TTL restante en ledgers por debajo del cual se renueva una entrada
+    pub threshold_ledgers: u32,
+    /// TTL en ledgers al que se extiende una entrada renovada
+    pub extend_to_ledgers: u32,
+}
+
+/// Evento de actualización del código (WASM) del contrato
+#[contractevent]
+#[derive(Clone)]
+pub struct ContractUpgradedEvent {
+    /// Hash del nuevo WASM instalado
+    pub new_wasm_hash: BytesN<32>,
+}
+
+/// Evento de migración de un balance a unidades de 7 decimales
+#[contractevent]
+#[derive(Clone)]
+pub struct BalanceMigratedEvent {
+    /// Cuenta migrada
+    pub account: Address,
+    /// Balance resultante en unidades mínimas
+    pub balance: i128,
+}
+
+/// Evento de cierre de una migración del esquema de almacenamiento
+#[contractevent]
+#[derive(Clone)]
+pub struct SchemaMigratedEvent {
+    /// Versión de esquema anterior
+    pub from_version: u32,
+    /// Versión de esquema vigente
+    pub to_version: u32,
+}
+
+/// Evento de asignación del rol de pauser
+#[contractevent]
+#[derive(Clone)]
+pub struct PauserSetEvent {
+    /// Dirección que puede pausar y reanudar el contrato
+    pub pauser: Address,
+}
+
+/// Evento de propuesta de traspaso de la administración
+#[contractevent]
+#[derive(Clone)]
+pub struct AdminTransferProposedEvent {
+    /// Administrador actual
+    pub current_admin: Address,
+    /// Administrador propuesto
+    pub new_admin: Address,
+    /// Último ledger en que el traspaso puede aceptarse
+    pub expiration_ledger: u32,
+}
+
+/// Evento de traspaso de la administración aceptado
+#[contractevent]
+#[derive(Clone)]
+pub struct AdminTransferredEvent {
+    /// Administrador anterior
+    pub previous_admin: Address,
+    /// Nuevo administrador
+    pub new_admin: Address,
+}
+
+/// Evento de cancelación de un traspaso de administración pendiente
+#[contractevent]
+#[derive(Clone)]
+pub struct AdminTransferCancelledEvent {
+    /// Administrador que cancela
+    pub admin: Address,
+    /// Administrador que había sido propuesto
+    pub new_admin: Address,
+}
+
+/// Evento de renuncia definitiva a la administración
+#[contractevent]
+#[derive(Clone)]
+pub struct AdminRenouncedEvent {
+    /// Último administrador
+    pub admin: Address,
 }
 
 /// Claves para el almacenamiento
@@ -69,9 +270,62 @@ pub enum DataKey {
     Admin,
     /// Balance de tokens por dirección (Persistent Storage)
     Balance(Address),
-    /// Asignación de gasto delegado (Persistent Storage)
-    /// Mapea (owner, spender) -> amount
+    /// Asignación de gasto delegado (Temporary Storage, vive hasta su expiración)
+    /// Mapea (owner, spender) -> AllowanceValue
     Allowance(Address, Address),
+    /// Direcciones con el rol de minter (Instance Storage)
+    Minters,
+    /// Total histórico de tokens acuñados (Instance Storage)
+    TotalMinted,
+    /// Total histórico de tokens quemados (Instance Storage)
+    TotalBurned,
+    /// Umbral y extensión del TTL de almacenamiento (Instance Storage)
+    TtlConfig,
+    /// Versión del esquema de almacenamiento (Instance Storage)
+    SchemaVersion,
+    /// Dirección con el rol de pauser (Instance Storage)
+    Pauser,
+    /// Traspaso de administración pendiente de aceptar (Instance Storage)
+    PendingAdmin,
+    /// Marca de renuncia a la administración; impide reinicializar (Instance Storage)
+    AdminRenounced,
+    /// Marca de balance ya reescalado durante la migración del esquema `1` (Persistent Storage)
+    BalanceMigrated(Address),
+}
+
+/// Traspaso de administración propuesto y aún no aceptado
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingAdmin {
+    /// Administrador propuesto, que debe aceptar con su firma
+    pub new_admin: Address,
+    /// Último ledger en que el traspaso puede aceptarse
+    pub expiration_ledger: u32,
+}
+
+/// Configuración de la extensión de TTL del almacenamiento
+/// 
+/// Cada lectura o escritura de un balance lo renueva (junto con la instancia)
+/// hasta `extend_to_ledgers` cuando su TTL restante cae por debajo de
+/// `threshold_ledgers`. Las asignaciones viven en Temporary Storage hasta su
+/// ledger de expiración y no se renuevan.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TtlConfig {
+    /// TTL restante en ledgers por debajo del cual se renueva una entrada
+    pub threshold_ledgers: u32,
+    /// TTL en ledgers al que se extiende una entrada renovada
+    pub extend_to_ledgers: u32,
+}
+
+/// Asignación de gasto delegado con expiración (SEP-41)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AllowanceValue {
+    /// Cantidad autorizada
+    pub amount: i128,
+    /// Ledger a partir del cual la asignación se considera 0
+    pub expiration_ledger: u32,
 }
 
 #[contractimpl]
@@ -85,36 +339,389 @@ impl CarbonToken {
     /// # Comportamiento
     /// Establece el nombre 'CARBONXO', símbolo 'CXO' y guarda el admin.
     pub fn initialize(env: Env, admin: Address) -> Result<(), TokenError> {
-        // Verificar que no ha sido inicializado ya
-        if env.storage().instance().has(&DataKey::Admin) {
+        // Verificar que no ha sido inicializado ya (aunque el admin haya renunciado)
+        if env.storage().instance().has(&DataKey::Admin)
+            || env.storage().instance().has(&DataKey::AdminRenounced)
+        {
             return Err(TokenError::NotInitialized);
         }
 
         // Guardar el admin en Instance Storage
         env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+        Self::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Obtiene la dirección del administrador, si existe
+    pub fn admin(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    /// Propone traspasar la administración a otra dirección (paso 1 de 2)
+    /// 
+    /// El traspaso solo ocurre cuando `new_admin` lo acepta con `accept_admin`
+    /// a más tardar en `expiration_ledger`. Una nueva propuesta reemplaza a la
+    /// pendiente.
+    /// 
+    /// # Errores
+    /// * `TokenError::NotInitialized` si el contrato no tiene admin
+    /// * `TokenError::InvalidNewAdmin` si `new_admin` es el admin actual
+    /// * `TokenError::InvalidExpirationLedger` si la expiración ya pasó
+    /// 
+    /// # Emite
+    /// * `AdminTransferProposedEvent`
+    pub fn propose_admin(env: Env, new_admin: Address, expiration_ledger: u32) -> Result<(), TokenError> {
+        // ✅ AUTORIZACIÓN: Solo el admin actual puede proponer su reemplazo
+        let admin = Self::require_admin(&env)?;
+
+        if new_admin == admin {
+            return Err(TokenError::InvalidNewAdmin);
+        }
+        if expiration_ledger < env.ledger().sequence() {
+            return Err(TokenError::InvalidExpirationLedger);
+        }
+
+        env.storage().instance().set(&DataKey::PendingAdmin, &PendingAdmin {
+            new_admin: new_admin.clone(),
+            expiration_ledger,
+        });
+
+        // ✅ EMITIR EVENTO
+        AdminTransferProposedEvent {
+            current_admin: admin,
+            new_admin,
+            expiration_ledger,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Acepta el traspaso de administración pendiente (paso 2 de 2)
+    /// 
+    /// Requiere la firma del administrador propuesto.
+    /// 
+    /// # Errores
+    /// * `TokenError::AdminTransferNotFound` si no hay traspaso pendiente
+    /// * `TokenError::AdminTransferExpired` si la propuesta ya expiró
+    /// 
+    /// # Emite
+    /// * `AdminTransferredEvent`
+    pub fn accept_admin(env: Env) -> Result<(), TokenError> {
+        let pending: PendingAdmin = env.storage().instance().get(&DataKey::PendingAdmin)
+            .ok_or(TokenError::AdminTransferNotFound)?;
+
+        // ✅ AUTORIZACIÓN: Solo el administrador propuesto puede aceptar
+        pending.new_admin.require_auth();
+
+        if env.ledger().sequence() > pending.expiration_ledger {
+            return Err(TokenError::AdminTransferExpired);
+        }
+
+        let previous_admin = Self::admin(env.clone()).ok_or(TokenError::NotInitialized)?;
+        env.storage().instance().set(&DataKey::Admin, &pending.new_admin);
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+
+        // ✅ EMITIR EVENTO
+        AdminTransferredEvent {
+            previous_admin,
+            new_admin: pending.new_admin,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Cancela el traspaso de administración pendiente
+    /// 
+    /// # Errores
+    /// * `TokenError::NotInitialized` si el contrato no tiene admin
+    /// * `TokenError::AdminTransferNotFound` si no hay traspaso pendiente
+    /// 
+    /// # Emite
+    /// * `AdminTransferCancelledEvent`
+    pub fn cancel_admin_transfer(env: Env) -> Result<(), TokenError> {
+        // ✅ AUTORIZACIÓN: Solo el admin actual puede cancelar
+        let admin = Self::require_admin(&env)?;
+
+        let pending: PendingAdmin = env.storage().instance().get(&DataKey::PendingAdmin)
+            .ok_or(TokenError::AdminTransferNotFound)?;
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+
+        // ✅ EMITIR EVENTO
+        AdminTransferCancelledEvent {
+            admin,
+            new_admin: pending.new_admin,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Renuncia de forma definitiva a la administración del contrato
+    /// 
+    /// Irreversible: ya no se pueden administrar minters, pauser, TTL ni
+    /// actualizaciones; se descarta cualquier traspaso pendiente y el contrato
+    /// no puede volver a inicializarse.
+    /// 
+    /// # Errores
+    /// * `TokenError::NotInitialized` si el contrato no tiene admin
+    /// 
+    /// # Emite
+    /// * `AdminRenouncedEvent`
+    pub fn renounce_admin(env: Env) -> Result<(), TokenError> {
+        // ✅ AUTORIZACIÓN: Solo el admin puede renunciar
+        let admin = Self::require_admin(&env)?;
+
+        env.storage().instance().remove(&DataKey::Admin);
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+        env.storage().instance().set(&DataKey::AdminRenounced, &true);
+
+        // ✅ EMITIR EVENTO
+        AdminRenouncedEvent { admin }.publish(&env);
 
         Ok(())
     }
 
+    /// Obtiene el traspaso de administración pendiente, si existe
+    pub fn pending_admin(env: Env) -> Option<PendingAdmin> {
+        env.storage().instance().get(&DataKey::PendingAdmin)
+    }
+
+    /// Actualiza el código (WASM) del contrato conservando balances y configuración
+    /// 
+    /// # Errores
+    /// * `TokenError::NotInitialized` si el contrato no ha sido inicializado
+    /// 
+    /// # Emite
+    /// * `ContractUpgradedEvent`
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), TokenError> {
+        // ✅ AUTORIZACIÓN: Solo el admin puede actualizar el código
+        Self::require_admin(&env)?;
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+
+        // ✅ EMITIR EVENTO
+        ContractUpgradedEvent { new_wasm_hash }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Obtiene la versión del esquema de almacenamiento
+    /// 
+    /// Los contratos desplegados antes de existir el marcador reportan `1`.
+    pub fn schema_version(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::SchemaVersion).unwrap_or(1)
+    }
+
+    /// Reescala a 7 decimales los balances en CXO enteros del esquema `1`
+    /// 
+    /// Tras actualizar un despliegue anterior a los decimales, cada balance
+    /// existente se multiplica por 10^7 para que coincida con los CO2e en kg
+    /// que migra CarbonCertifier. Se ejecuta con el contrato pausado, para que
+    /// no se acuñen ni transfieran unidades nuevas sobre balances sin migrar,
+    /// en lotes de hasta 50 cuentas; la migración se cierra con `finish_migration`.
+    /// 
+    /// Las asignaciones del esquema `1` (Persistent Storage, en CXO enteros y sin
+    /// expiración) no se migran: dejan de leerse y cuentan como 0, por lo que cada
+    /// dueño debe volver a otorgarlas con `approve` tras la migración.
+    /// 
+    /// # Errores
+    /// * `TokenError::NotInitialized` si el contrato no tiene admin
+    /// * `PausableError::ExpectedPause` si el contrato no está pausado
+    /// * `TokenError::AlreadyMigrated` si el esquema ya está al día o alguna cuenta ya se migró
+    /// * `TokenError::InvalidMigrationBatch` si el lote está vacío o excede 50 cuentas
+    /// * `TokenError::InvalidAmount` si algún balance reescalado desborda
+    /// 
+    /// # Emite
+    /// * `BalanceMigratedEvent` por cada cuenta
+    pub fn migrate_balances(env: Env, accounts: Vec<Address>) -> Result<(), TokenError> {
+        // ✅ AUTORIZACIÓN: Solo el admin migra balances, con los movimientos pausados
+        Self::require_admin(&env)?;
+        pausable::when_paused(&env);
+
+        if Self::schema_version(env.clone()) >= SCHEMA_VERSION {
+            return Err(TokenError::AlreadyMigrated);
+        }
+        if accounts.is_empty() || accounts.len() > MAX_MIGRATION_BATCH {
+            return Err(TokenError::InvalidMigrationBatch);
+        }
+
+        for account in accounts.iter() {
+            let marker_key = DataKey::BalanceMigrated(account.clone());
+            if env.storage().persistent().has(&marker_key) {
+                return Err(TokenError::AlreadyMigrated);
+            }
+            env.storage().persistent().set(&marker_key, &true);
+
+            // ✅ UNIDADES: 1 CXO entero pasa a 10^7 unidades mínimas
+            let balance = Self::get_balance(&env, &account)
+                .checked_mul(UNITS_PER_CXO)
+                .ok_or(TokenError::InvalidAmount)?;
+            if balance > 0 {
+                Self::set_balance(&env, &account, balance);
+            }
+
+            BalanceMigratedEvent { account, balance }.publish(&env);
+        }
+
+        Ok(())
+    }
+
+    /// Cierra la migración de balances y marca el esquema vigente
+    /// 
+    /// Reescala a 7 decimales los totales de acuñados y quemados. Se ejecuta
+    /// con el contrato pausado, después de migrar todas las cuentas con balance.
+    /// 
+    /// # Errores
+    /// * `TokenError::NotInitialized` si el contrato no tiene admin
+    /// * `PausableError::ExpectedPause` si el contrato no está pausado
+    /// * `TokenError::AlreadyMigrated` si el esquema ya está al día
+    /// * `TokenError::InvalidAmount` si algún total reescalado desborda
+    /// 
+    /// # Emite
+    /// * `SchemaMigratedEvent`
+    pub fn finish_migration(env: Env) -> Result<(), TokenError> {
+        // ✅ AUTORIZACIÓN: Solo el admin cierra la migración, con los movimientos pausados
+        Self::require_admin(&env)?;
+        pausable::when_paused(&env);
+
+        let from_version = Self::schema_version(env.clone());
+        if from_version >= SCHEMA_VERSION {
+            return Err(TokenError::AlreadyMigrated);
+        }
+
+        for key in [DataKey::TotalMinted, DataKey::TotalBurned] {
+            let total: i128 = env.storage().instance().get(&key).unwrap_or(0);
+            let total = total.checked_mul(UNITS_PER_CXO).ok_or(TokenError::InvalidAmount)?;
+            env.storage().instance().set(&key, &total);
+        }
+        env.storage().instance().set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+
+        // ✅ EMITIR EVENTO
+        SchemaMigratedEvent {
+            from_version,
+            to_version: SCHEMA_VERSION,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Asigna el rol de pauser, que puede pausar y reanudar el contrato
+    /// 
+    /// El admin conserva también la capacidad de pausar.
+    /// 
+    /// # Errores
+    /// * `TokenError::NotInitialized` si el contrato no ha sido inicializado
+    /// 
+    /// # Emite
+    /// * `PauserSetEvent`
+    pub fn set_pauser(env: Env, pauser: Address) -> Result<(), TokenError> {
+        // ✅ AUTORIZACIÓN: Solo el admin administra roles
+        Self::require_admin(&env)?;
+
+        env.storage().instance().set(&DataKey::Pauser, &pauser);
+
+        // ✅ EMITIR EVENTO
+        PauserSetEvent { pauser }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Obtiene la dirección con el rol de pauser, si existe
+    pub fn pauser(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Pauser)
+    }
+
+    /// Otorga el rol de minter a una dirección (p. ej. el contrato CarbonCertifier)
+    /// 
+    /// Solo el admin puede administrar los minters.
+    /// 
+    /// # Errores
+    /// * `TokenError::NotInitialized` si el contrato no ha sido inicializado
+    /// * `TokenError::MinterAlreadyExists` si la dirección ya es minter
+    /// 
+    /// # Emite
+    /// * `MinterGrantedEvent`
+    pub fn grant_minter(env: Env, minter: Address) -> Result<(), TokenError> {
+        // ✅ AUTORIZACIÓN: Solo el admin administra roles
+        Self::require_admin(&env)?;
+
+        let mut minters = Self::minters(env.clone());
+        if minters.contains(&minter) {
+            return Err(TokenError::MinterAlreadyExists);
+        }
+        minters.push_back(minter.clone());
+        env.storage().instance().set(&DataKey::Minters, &minters);
+
+        // ✅ EMITIR EVENTO
+        MinterGrantedEvent { minter }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Revoca el rol de minter de una dirección
+    /// 
+    /// # Errores
+    /// * `TokenError::NotInitialized` si el contrato no ha sido inicializado
+    /// * `TokenError::MinterNotFound` si la dirección no es minter
+    /// 
+    /// # Emite
+    /// * `MinterRevokedEvent`
+    pub fn revoke_minter(env: Env, minter: Address) -> Result<(), TokenError> {
+        // ✅ AUTORIZACIÓN: Solo el admin administra roles
+        Self::require_admin(&env)?;
+
+        let mut minters = Self::minters(env.clone());
+        let index = minters.first_index_of(&minter).ok_or(TokenError::MinterNotFound)?;
+        minters.remove(index);
+        env.storage().instance().set(&DataKey::Minters, &minters);
+
+        // ✅ EMITIR EVENTO
+        MinterRevokedEvent { minter }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Lista las direcciones que tienen actualmente el rol de minter
+    pub fn minters(env: Env) -> Vec<Address> {
+        env.storage().instance().get(&DataKey::Minters).unwrap_or(Vec::new(&env))
+    }
+
+    /// Indica si una dirección tiene el rol de minter
+    pub fn is_minter(env: Env, account: Address) -> bool {
+        Self::minters(env).contains(&account)
+    }
+
     /// Acuña nuevos tokens CARBONXO
     /// 
-    /// Solo el admin puede acuñar tokens.
+    /// Solo las direcciones con el rol de minter pueden acuñar tokens.
     /// 
     /// # Argumentos
     /// * `env` - El entorno del contrato
+    /// * `minter` - Dirección con rol de minter que autoriza la acuñación
     /// * `to` - Dirección que recibirá los tokens
     /// * `amount` - Cantidad de tokens a acuñar
     /// 
     /// # Errores
-    /// * `TokenError::Unauthorized` si el llamador no es el admin
+    /// * `PausableError::EnforcedPause` si el contrato está pausado
+    /// * `TokenError::Unauthorized` si `minter` no tiene el rol de minter
     /// * `TokenError::InvalidAmount` si amount <= 0
-    /// * `TokenError::NotInitialized` si el contrato no ha sido inicializado
     /// 
     /// # Emite
     /// * `MintEvent` con los datos de la acuñación
-    pub fn mint(env: Env, to: Address, amount: i128) -> Result<(), TokenError> {
-        // ✅ AUTORIZACIÓN: Solo el admin puede acuñar
-        Self::require_admin(&env)?;
+    pub fn mint(env: Env, minter: Address, to: Address, amount: i128) -> Result<(), TokenError> {
+        // ✅ PAUSA: Bloqueado mientras el contrato está pausado
+        pausable::when_not_paused(&env);
+
+        // ✅ AUTORIZACIÓN: Solo un minter puede acuñar
+        minter.require_auth();
+        if !Self::is_minter(env.clone(), minter) {
+            return Err(TokenError::Unauthorized);
+        }
 
         // ✅ VALIDACIÓN: La cantidad debe ser positiva
         if amount <= 0 {
@@ -126,64 +733,278 @@ impl CarbonToken {
         let new_balance = current_balance + amount;
         Self::set_balance(&env, &to, new_balance);
 
+        // ✅ ACTUALIZAR CONTADOR DE OFERTA
+        Self::add_to_counter(&env, DataKey::TotalMinted, amount);
+
         // ✅ EMITIR EVENTO
         MintEvent { to, amount }.publish(&env);
 
         Ok(())
     }
 
-    /// Transfiere tokens entre direcciones
+    /// Recupera y quema tokens CARBONXO de una cuenta
+    /// 
+    /// Solo las direcciones con el rol de minter pueden recuperar tokens; se
+    /// usa para retirar de circulación los CXO de un certificado revocado.
+    /// No requiere la firma de `from`.
     /// 
     /// # Argumentos
     /// * `env` - El entorno del contrato
-    /// * `from` - Dirección del remitente
-    /// * `to` - Dirección del receptor
-    /// * `amount` - Cantidad de tokens a transferir
+    /// * `minter` - Dirección con rol de minter que autoriza la recuperación
+    /// * `from` - Dirección cuyos tokens se recuperan
+    /// * `amount` - Cantidad de tokens a recuperar
     /// 
     /// # Errores
-    /// * `TokenError::Unauthorized` si 'from' no está autorizado
-    /// * `TokenError::InsufficientBalance` si 'from' no tiene suficientes tokens
+    /// * `TokenError::Unauthorized` si `minter` no tiene el rol de minter
     /// * `TokenError::InvalidAmount` si amount <= 0
+    /// * `TokenError::InsufficientBalance` si 'from' no tiene suficientes tokens
     /// 
     /// # Emite
-    /// * `TransferEvent` con los datos de la transferencia
-    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) -> Result<(), TokenError> {
-        // ✅ AUTORIZACIÓN: 'from' debe firmar la transacción
-        from.require_auth();
+    /// * `ClawbackEvent` con los datos de la recuperación
+    pub fn clawback(env: Env, minter: Address, from: Address, amount: i128) -> Result<(), TokenError> {
+        // ✅ AUTORIZACIÓN: Solo un minter puede recuperar
+        minter.require_auth();
+        if !Self::is_minter(env.clone(), minter) {
+            return Err(TokenError::Unauthorized);
+        }
 
-        // ✅ VALIDACIÓN: La cantidad debe ser positiva
+        // ✅ VALIDACIÓN: La cantidad debe ser positiva y estar disponible
         if amount <= 0 {
             return Err(TokenError::InvalidAmount);
         }
-
-        // Verificar balance suficiente
         let from_balance = Self::get_balance(&env, &from);
         if from_balance < amount {
             return Err(TokenError::InsufficientBalance);
         }
 
-        // Actualizar balances
         Self::set_balance(&env, &from, from_balance - amount);
-        
-        let to_balance = Self::get_balance(&env, &to);
-        Self::set_balance(&env, &to, to_balance + amount);
+        Self::add_to_counter(&env, DataKey::TotalBurned, amount);
 
         // ✅ EMITIR EVENTO
-        TransferEvent { from, to, amount }.publish(&env);
+        ClawbackEvent { from, amount }.publish(&env);
 
         Ok(())
     }
 
-    /// Consulta el balance de tokens de una dirección
+    /// Obtiene la oferta circulante de CXO (acuñados - quemados)
     /// 
-    /// # Argumentos
-    /// * `env` - El entorno del contrato
-    /// * `id` - Dirección del usuario
+    /// # Retorna
+    /// `i128` - Total de tokens en circulación
+    pub fn total_supply(env: Env) -> i128 {
+        Self::total_minted(env.clone()) - Self::total_burned(env)
+    }
+
+    /// Obtiene el total histórico de tokens CXO acuñados
     /// 
     /// # Retorna
-    /// `i128` - Balance de tokens CARBONXO
-    pub fn balance(env: Env, id: Address) -> i128 {
-        Self::get_balance(&env, &id)
+    /// `i128` - Total de tokens acuñados desde la inicialización
+    pub fn total_minted(env: Env) -> i128 {
+        env.storage().instance().get(&DataKey::TotalMinted).unwrap_or(0)
+    }
+
+    /// Obtiene el total histórico de tokens CXO quemados
+    /// 
+    /// # Retorna
+    /// `i128` - Total de tokens quemados (p. ej. por retiro de certificados)
+    pub fn total_burned(env: Env) -> i128 {
+        env.storage().instance().get(&DataKey::TotalBurned).unwrap_or(0)
+    }
+
+    /// Configura cuándo y hasta dónde se renueva el TTL de los balances
+    /// 
+    /// # Errores
+    /// * `TokenError::NotInitialized` si el contrato no ha sido inicializado
+    /// * `TokenError::InvalidTtlConfig` si `extend_to_ledgers` no supera a
+    ///   `threshold_ledgers` o excede el TTL máximo de la red
+    /// 
+    /// # Emite
+    /// * `TtlConfigUpdatedEvent`
+    pub fn set_ttl_config(env: Env, config: TtlConfig) -> Result<(), TokenError> {
+        // ✅ AUTORIZACIÓN: Solo el admin configura el almacenamiento
+        Self::require_admin(&env)?;
+
+        if config.extend_to_ledgers <= config.threshold_ledgers
+            || config.extend_to_ledgers > env.storage().max_ttl()
+        {
+            return Err(TokenError::InvalidTtlConfig);
+        }
+
+        env.storage().instance().set(&DataKey::TtlConfig, &config);
+        Self::extend_instance_ttl(&env);
+
+        // ✅ EMITIR EVENTO
+        TtlConfigUpdatedEvent {
+            threshold_ledgers: config.threshold_ledgers,
+            extend_to_ledgers: config.extend_to_ledgers,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Obtiene la configuración de TTL vigente
+    /// 
+    /// Por defecto los balances se renuevan a 2,073,600 ledgers (~120 días)
+    /// cuando les quedan menos de 518,400 (~30 días).
+    pub fn get_ttl_config(env: Env) -> TtlConfig {
+        env.storage().instance().get(&DataKey::TtlConfig).unwrap_or(TtlConfig {
+            threshold_ledgers: DEFAULT_TTL_THRESHOLD_LEDGERS,
+            extend_to_ledgers: DEFAULT_TTL_EXTEND_TO_LEDGERS,
+        })
+    }
+
+    /// Renueva el TTL del balance de una cuenta y el de la instancia del contrato
+    /// 
+    /// No requiere autorización: cualquiera puede pagar la renta para que un
+    /// balance no se archive.
+    pub fn bump_account(env: Env, account: Address) {
+        let key = DataKey::Balance(account);
+        if env.storage().persistent().has(&key) {
+            Self::extend_balance_ttl(&env, &key);
+        }
+        Self::extend_instance_ttl(&env);
+    }
+
+    // =========================================================================
+    // Funciones privadas auxiliares
+    // =========================================================================
+
+    /// Verifica que el llamador sea el admin y retorna su dirección
+    fn require_admin(env: &Env) -> Result<Address, TokenError> {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin)
+            .ok_or(TokenError::NotInitialized)?;
+        admin.require_auth();
+        Ok(admin)
+    }
+
+    /// Verifica que el llamador sea el pauser o el admin
+    fn require_pauser(env: &Env, caller: &Address) {
+        caller.require_auth();
+
+        let pauser: Option<Address> = env.storage().instance().get(&DataKey::Pauser);
+        let admin: Option<Address> = env.storage().instance().get(&DataKey::Admin);
+        if pauser.as_ref() != Some(caller) && admin.as_ref() != Some(caller) {
+            panic_with_error!(env, TokenError::Unauthorized);
+        }
+    }
+
+    /// Obtiene el balance de una dirección
+    fn get_balance(env: &Env, address: &Address) -> i128 {
+        let key = DataKey::Balance(address.clone());
+        match env.storage().persistent().get(&key) {
+            Some(balance) => {
+                Self::extend_balance_ttl(env, &key);
+                balance
+            }
+            None => 0,
+        }
+    }
+
+    /// Establece el balance de una dirección
+    fn set_balance(env: &Env, address: &Address, balance: i128) {
+        let key = DataKey::Balance(address.clone());
+        env.storage().persistent().set(&key, &balance);
+        Self::extend_balance_ttl(env, &key);
+    }
+
+    /// Renueva el TTL de un balance existente y el de la instancia
+    fn extend_balance_ttl(env: &Env, key: &DataKey) {
+        let config = Self::get_ttl_config(env.clone());
+        env.storage().persistent().extend_ttl(key, config.threshold_ledgers, config.extend_to_ledgers);
+        env.storage().instance().extend_ttl(config.threshold_ledgers, config.extend_to_ledgers);
+    }
+
+    /// Renueva el TTL de la instancia del contrato (admin, minters y contadores)
+    fn extend_instance_ttl(env: &Env) {
+        let config = Self::get_ttl_config(env.clone());
+        env.storage().instance().extend_ttl(config.threshold_ledgers, config.extend_to_ledgers);
+    }
+
+    /// Obtiene la asignación vigente de (from, spender); las expiradas valen 0
+    fn get_allowance(env: &Env, from: &Address, spender: &Address) -> AllowanceValue {
+        let key = DataKey::Allowance(from.clone(), spender.clone());
+        match env.storage().temporary().get::<DataKey, AllowanceValue>(&key) {
+            Some(allowance) if allowance.expiration_ledger >= env.ledger().sequence() => allowance,
+            Some(allowance) => AllowanceValue {
+                amount: 0,
+                expiration_ledger: allowance.expiration_ledger,
+            },
+            None => AllowanceValue {
+                amount: 0,
+                expiration_ledger: 0,
+            },
+        }
+    }
+
+    /// Guarda la asignación de (from, spender) y extiende su TTL hasta la expiración
+    fn set_allowance(env: &Env, from: &Address, spender: &Address, amount: i128, expiration_ledger: u32) {
+        if amount > 0 && expiration_ledger < env.ledger().sequence() {
+            panic_with_error!(env, TokenError::InvalidExpirationLedger);
+        }
+
+        let key = DataKey::Allowance(from.clone(), spender.clone());
+        let allowance = AllowanceValue {
+            amount,
+            expiration_ledger,
+        };
+        env.storage().temporary().set(&key, &allowance);
+
+        if amount > 0 {
+            let live_for = expiration_ledger - env.ledger().sequence();
+            env.storage().temporary().extend_ttl(&key, live_for, live_for);
+        }
+    }
+
+    /// Consume `amount` de la asignación de `spender` sobre `from`
+    fn spend_allowance(env: &Env, from: &Address, spender: &Address, amount: i128) {
+        let allowance = Self::get_allowance(env, from, spender);
+        if allowance.amount < amount {
+            panic_with_error!(env, TokenError::InsufficientAllowance);
+        }
+        Self::set_allowance(env, from, spender, allowance.amount - amount, allowance.expiration_ledger);
+    }
+
+    /// Resta `amount` del balance de `from` validando la cantidad y el saldo
+    fn spend_balance(env: &Env, from: &Address, amount: i128) {
+        // ✅ VALIDACIÓN: La cantidad debe ser positiva
+        if amount <= 0 {
+            panic_with_error!(env, TokenError::InvalidAmount);
+        }
+
+        // Verificar balance suficiente
+        let from_balance = Self::get_balance(env, from);
+        if from_balance < amount {
+            panic_with_error!(env, TokenError::InsufficientBalance);
+        }
+
+        Self::set_balance(env, from, from_balance - amount);
+    }
+
+    /// Suma `amount` a un contador de oferta en Instance Storage
+    fn add_to_counter(env: &Env, key: DataKey, amount: i128) {
+        let current: i128 = env.storage().instance().get(&key).unwrap_or(0);
+        env.storage().instance().set(&key, &(current + amount));
+    }
+
+    /// Suma `amount` al balance de `to`
+    fn receive_balance(env: &Env, to: &Address, amount: i128) {
+        let to_balance = Self::get_balance(env, to);
+        Self::set_balance(env, to, to_balance + amount);
+    }
+}
+
+/// Interfaz estándar de token fungible SEP-41
+/// 
+/// Los errores se reportan con `panic_with_error!` usando `TokenError`, ya que
+/// la interfaz estándar no retorna `Result`.
+#[contractimpl]
+impl TokenInterface for CarbonToken {
+    /// Consulta la cantidad de tokens que un operador puede gastar en nombre del dueño
+    /// 
+    /// # Retorna
+    /// `i128` - Cantidad de tokens autorizados (0 si no existe o expiró)
+    fn allowance(env: Env, from: Address, spender: Address) -> i128 {
+        Self::get_allowance(&env, &from, &spender).amount
     }
 
     /// Aprueba a un operador para gastar tokens en nombre del dueño
@@ -193,136 +1014,200 @@ impl CarbonToken {
     /// * `from` - Dirección del dueño (propietario de los tokens)
     /// * `spender` - Dirección del operador autorizado
     /// * `amount` - Cantidad de tokens autorizados
+    /// * `expiration_ledger` - Ledger a partir del cual la asignación expira
     /// 
     /// # Errores
     /// * `TokenError::InvalidAmount` si amount < 0
+    /// * `TokenError::InvalidExpirationLedger` si amount > 0 y la expiración ya pasó
     /// 
     /// # Emite
     /// * `ApprovalEvent` con los datos de la aprobación
-    pub fn approve(env: Env, from: Address, spender: Address, amount: i128) -> Result<(), TokenError> {
+    fn approve(env: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
         // ✅ AUTORIZACIÓN: Solo el dueño puede aprobar gastos
         from.require_auth();
 
         // ✅ VALIDACIÓN: La cantidad no debe ser negativa
         if amount < 0 {
-            return Err(TokenError::InvalidAmount);
+            panic_with_error!(&env, TokenError::InvalidAmount);
         }
 
-        // Almacenar la aprobación en Persistent Storage
-        let allowance_key = DataKey::Allowance(from.clone(), spender.clone());
-        env.storage().persistent().set(&allowance_key, &amount);
+        Self::set_allowance(&env, &from, &spender, amount, expiration_ledger);
 
         // ✅ EMITIR EVENTO
         ApprovalEvent {
             owner: from,
             spender,
             amount,
+            expiration_ledger,
         }
         .publish(&env);
+    }
 
-        Ok(())
+    /// Consulta el balance de tokens de una dirección
+    /// 
+    /// # Retorna
+    /// `i128` - Balance de tokens CARBONXO
+    fn balance(env: Env, id: Address) -> i128 {
+        Self::get_balance(&env, &id)
     }
 
-    /// Consulta la cantidad de tokens que un operador puede gastar en nombre del dueño
+    /// Transfiere tokens entre direcciones
     /// 
     /// # Argumentos
     /// * `env` - El entorno del contrato
-    /// * `from` - Dirección del dueño
-    /// * `spender` - Dirección del operador
+    /// * `from` - Dirección del remitente
+    /// * `to` - Dirección del receptor (admite cuentas multiplexadas)
+    /// * `amount` - Cantidad de tokens a transferir
     /// 
-    /// # Retorna
-    /// `i128` - Cantidad de tokens autorizados (0 si no existe aprobación)
-    pub fn allowance(env: Env, from: Address, spender: Address) -> i128 {
-        let allowance_key = DataKey::Allowance(from, spender);
-        env.storage().persistent().get(&allowance_key).unwrap_or(0)
+    /// # Errores
+    /// * `PausableError::EnforcedPause` si el contrato está pausado
+    /// * `TokenError::InsufficientBalance` si 'from' no tiene suficientes tokens
+    /// * `TokenError::InvalidAmount` si amount <= 0
+    /// 
+    /// # Emite
+    /// * `TransferEvent`, o `TransferMuxedEvent` si `to` tiene ID multiplexado
+    fn transfer(env: Env, from: Address, to: MuxedAddress, amount: i128) {
+        // ✅ PAUSA: Bloqueado mientras el contrato está pausado
+        pausable::when_not_paused(&env);
+
+        // ✅ AUTORIZACIÓN: 'from' debe firmar la transacción
+        from.require_auth();
+
+        let to_address = to.address();
+        Self::spend_balance(&env, &from, amount);
+        Self::receive_balance(&env, &to_address, amount);
+
+        // ✅ EMITIR EVENTO: El ID multiplexado solo viaja si existe (CAP-67)
+        match to.id() {
+            Some(to_muxed_id) => TransferMuxedEvent {
+                from,
+                to: to_address,
+                to_muxed_id,
+                amount,
+            }
+            .publish(&env),
+            None => TransferEvent {
+                from,
+                to: to_address,
+                amount,
+            }
+            .publish(&env),
+        }
     }
 
     /// Transfiere tokens desde una dirección a otra en nombre del dueño
     /// 
     /// El operador (spender) debe haber sido previamente aprobado por el dueño (from)
-    /// y tener suficiente asignación.
-    /// 
-    /// # Argumentos
-    /// * `env` - El entorno del contrato
-    /// * `spender` - Dirección del operador autorizado (firmante de la transacción)
-    /// * `from` - Dirección del dueño (remitente de los tokens)
-    /// * `to` - Dirección del receptor
-    /// * `amount` - Cantidad de tokens a transferir
+    /// y tener suficiente asignación vigente.
     /// 
     /// # Errores
-    /// * `TokenError::Unauthorized` si 'spender' no está autenticado
+    /// * `PausableError::EnforcedPause` si el contrato está pausado
     /// * `TokenError::InsufficientBalance` si 'from' no tiene suficientes tokens
     /// * `TokenError::InsufficientAllowance` si no hay suficiente asignación
     /// * `TokenError::InvalidAmount` si amount <= 0
     /// 
     /// # Emite
     /// * `TransferEvent` con los datos de la transferencia
-    pub fn transfer_from(
-        env: Env,
-        spender: Address,
-        from: Address,
-        to: Address,
-        amount: i128,
-    ) -> Result<(), TokenError> {
+    fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        // ✅ PAUSA: Bloqueado mientras el contrato está pausado
+        pausable::when_not_paused(&env);
+
         // ✅ AUTORIZACIÓN: El operador debe firmar la transacción
         spender.require_auth();
 
-        // ✅ VALIDACIÓN: La cantidad debe ser positiva
-        if amount <= 0 {
-            return Err(TokenError::InvalidAmount);
-        }
+        Self::spend_balance(&env, &from, amount);
+        Self::spend_allowance(&env, &from, &spender, amount);
+        Self::receive_balance(&env, &to, amount);
 
-        // Verificar balance suficiente del dueño
-        let from_balance = Self::get_balance(&env, &from);
-        if from_balance < amount {
-            return Err(TokenError::InsufficientBalance);
-        }
+        // ✅ EMITIR EVENTO
+        TransferEvent { from, to, amount }.publish(&env);
+    }
 
-        // Verificar asignación suficiente del operador
-        let current_allowance = Self::allowance(env.clone(), from.clone(), spender.clone());
-        if current_allowance < amount {
-            return Err(TokenError::InsufficientAllowance);
-        }
+    /// Quema tokens CARBONXO del balance de una dirección
+    /// 
+    /// Usado al retirar certificados de carbono para que la oferta de CXO
+    /// coincida siempre con el tonelaje certificado no retirado.
+    /// 
+    /// # Errores
+    /// * `TokenError::InsufficientBalance` si 'from' no tiene suficientes tokens
+    /// * `TokenError::InvalidAmount` si amount <= 0
+    /// 
+    /// # Emite
+    /// * `BurnEvent` con los datos de la quema
+    fn burn(env: Env, from: Address, amount: i128) {
+        // ✅ AUTORIZACIÓN: 'from' debe firmar la transacción
+        from.require_auth();
 
-        // Actualizar balances
-        Self::set_balance(&env, &from, from_balance - amount);
-        
-        let to_balance = Self::get_balance(&env, &to);
-        Self::set_balance(&env, &to, to_balance + amount);
+        Self::spend_balance(&env, &from, amount);
+        Self::add_to_counter(&env, DataKey::TotalBurned, amount);
 
-        // Reducir la asignación
-        let new_allowance = current_allowance - amount;
-        let allowance_key = DataKey::Allowance(from.clone(), spender);
-        env.storage().persistent().set(&allowance_key, &new_allowance);
+        // ✅ EMITIR EVENTO
+        BurnEvent { from, amount }.publish(&env);
+    }
+
+    /// Quema tokens de 'from' consumiendo la asignación de 'spender'
+    /// 
+    /// # Errores
+    /// * `TokenError::InsufficientBalance` si 'from' no tiene suficientes tokens
+    /// * `TokenError::InsufficientAllowance` si no hay suficiente asignación
+    /// * `TokenError::InvalidAmount` si amount <= 0
+    /// 
+    /// # Emite
+    /// * `BurnEvent` con los datos de la quema
+    fn burn_from(env: Env, spender: Address, from: Address, amount: i128) {
+        // ✅ AUTORIZACIÓN: El operador debe firmar la transacción
+        spender.require_auth();
+
+        Self::spend_balance(&env, &from, amount);
+        Self::spend_allowance(&env, &from, &spender, amount);
+        Self::add_to_counter(&env, DataKey::TotalBurned, amount);
 
         // ✅ EMITIR EVENTO
-        TransferEvent { from, to, amount }.publish(&env);
+        BurnEvent { from, amount }.publish(&env);
+    }
 
-        Ok(())
+    /// Decimales del token
+    fn decimals(_env: Env) -> u32 {
+        TOKEN_DECIMALS
     }
 
-    // =========================================================================
-    // Funciones privadas auxiliares
-    // =========================================================================
+    /// Nombre del token ("CARBONXO")
+    fn name(env: Env) -> String {
+        String::from_str(&env, TOKEN_NAME)
+    }
 
-    /// Verifica que el llamador sea el admin
-    fn require_admin(env: &Env) -> Result<(), TokenError> {
-        let admin: Address = env.storage().instance().get(&DataKey::Admin)
-            .ok_or(TokenError::NotInitialized)?;
-        admin.require_auth();
-        Ok(())
+    /// Símbolo del token ("CXO")
+    fn symbol(env: Env) -> String {
+        String::from_str(&env, TOKEN_SYMBOL)
     }
+}
 
-    /// Obtiene el balance de una dirección
-    fn get_balance(env: &Env, address: &Address) -> i128 {
-        let key = DataKey::Balance(address.clone());
-        env.storage().persistent().get(&key).unwrap_or(0)
+/// Freno de emergencia: mientras está pausado se bloquean `mint`, `transfer` y
+/// `transfer_from`; las consultas siguen disponibles.
+#[contractimpl]
+impl Pausable for CarbonToken {
+    /// Indica si el contrato está pausado
+    fn paused(e: &Env) -> bool {
+        pausable::paused(e)
     }
 
-    /// Establece el balance de una dirección
-    fn set_balance(env: &Env, address: &Address, balance: i128) {
-        let key = DataKey::Balance(address.clone());
-        env.storage().persistent().set(&key, &balance);
+    /// Pausa el contrato
+    /// 
+    /// # Errores
+    /// * `TokenError::Unauthorized` si `caller` no es el pauser ni el admin
+    /// * `PausableError::EnforcedPause` si ya está pausado
+    fn pause(e: &Env, caller: Address) {
+        Self::require_pauser(e, &caller);
+        pausable::pause(e);
     }
-}
 
+    /// Reanuda el contrato
+    /// 
+    /// # Errores
+    /// * `TokenError::Unauthorized` si `caller` no es el pauser ni el admin
+    /// * `PausableError::ExpectedPause` si no está pausado
+    fn unpause(e: &Env, caller: Address) {
+        Self::require_pauser(e, &caller);
+        pausable::unpause(e);
+    }
+}